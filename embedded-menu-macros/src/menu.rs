@@ -0,0 +1,444 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Expr, Fields, GenericParam, Ident,
+    LitInt, LitStr, Type, WherePredicate,
+};
+
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+#[derive(Default)]
+struct NumericOptions {
+    min: Option<Expr>,
+    max: Option<Expr>,
+    step: Option<Expr>,
+    decimals: Option<LitInt>,
+}
+
+impl NumericOptions {
+    fn is_set(&self) -> bool {
+        self.min.is_some() || self.max.is_some() || self.step.is_some() || self.decimals.is_some()
+    }
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    label: Option<LitStr>,
+    section: Option<LitStr>,
+    skip: bool,
+    numeric: NumericOptions,
+}
+
+impl FieldOptions {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("menu")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    options.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("section") {
+                    options.section = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    options.skip = true;
+                } else if meta.path.is_ident("min") {
+                    options.numeric.min = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max") {
+                    options.numeric.max = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("step") {
+                    options.numeric.step = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("decimals") {
+                    options.numeric.decimals = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown menu field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+struct MenuOptions {
+    title: LitStr,
+    event: Ident,
+}
+
+impl MenuOptions {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut title = None;
+        let mut event = None;
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("menu"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("title") {
+                    title = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("event") {
+                    event = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown menu attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(Self {
+            title: title
+                .unwrap_or_else(|| LitStr::new(&input.ident.to_string(), Span::call_site())),
+            event: event.unwrap_or_else(|| format_ident!("{}Event", input.ident)),
+        })
+    }
+}
+
+/// A single line of the generated menu.
+enum Line<'a> {
+    Section(LitStr),
+    Select {
+        label: LitStr,
+        field: &'a Ident,
+        variant: Ident,
+        ty: &'a Type,
+    },
+    Numeric {
+        label: LitStr,
+        field: &'a Ident,
+        variant: Ident,
+        ty: &'a Type,
+        options: Box<NumericOptions>,
+    },
+}
+
+impl Line<'_> {
+    /// The type of the menu item, used to spell out the type of the builder.
+    fn item_type(&self, event: &TokenStream) -> TokenStream {
+        match self {
+            Line::Section(_) => quote! {
+                embedded_menu::items::MenuItem<&'static str, #event, (), false>
+            },
+            Line::Select { ty, .. } => quote! {
+                embedded_menu::items::MenuItem<&'static str, #event, #ty, true>
+            },
+            Line::Numeric { ty, .. } => quote! {
+                embedded_menu::items::NumericItem<&'static str, #event, #ty>
+            },
+        }
+    }
+
+    /// The builder method call that appends the menu item.
+    fn add_item(&self, event: &Ident) -> TokenStream {
+        match self {
+            Line::Section(title) => quote! {
+                .add_section_title(#title)
+            },
+            Line::Select {
+                label,
+                field,
+                variant,
+                ..
+            } => quote! {
+                .add_item(#label, self.#field.clone(), #event::#variant)
+            },
+            Line::Numeric {
+                label,
+                field,
+                variant,
+                ty,
+                options,
+            } => {
+                let min = options
+                    .min
+                    .as_ref()
+                    .map_or_else(|| quote! { <#ty>::MIN }, |min| quote! { #min });
+                let max = options
+                    .max
+                    .as_ref()
+                    .map_or_else(|| quote! { <#ty>::MAX }, |max| quote! { #max });
+                let step = options
+                    .step
+                    .as_ref()
+                    .map(|step| quote! { .with_step(#step) });
+                let decimals = options
+                    .decimals
+                    .as_ref()
+                    .map(|decimals| quote! { .with_decimals(#decimals) });
+
+                quote! {
+                    .add_menu_item(
+                        embedded_menu::items::NumericItem::new(
+                            #label,
+                            self.#field.clone(),
+                            #min,
+                            #max,
+                        )
+                            .with_value_converter(#event::#variant)
+                            #step
+                            #decimals
+                    )
+                }
+            }
+        }
+    }
+}
+
+fn is_integer(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    path.qself.is_none()
+        && path
+            .path
+            .get_ident()
+            .is_some_and(|ident| INTEGER_TYPES.iter().any(|int| ident == int))
+}
+
+fn variant_name(field: &Ident) -> Ident {
+    let name = field.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+
+    let mut variant = String::with_capacity(name.len());
+    let mut uppercase = true;
+    for c in name.chars() {
+        if c == '_' {
+            uppercase = true;
+        } else if uppercase {
+            variant.extend(c.to_uppercase());
+            uppercase = false;
+        } else {
+            variant.push(c);
+        }
+    }
+
+    Ident::new(&variant, field.span())
+}
+
+pub fn expand_menu(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Menu can only be derived on structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Menu can only be derived on structs with named fields",
+        ));
+    };
+
+    let options = MenuOptions::parse(&input)?;
+
+    let mut lines = Vec::new();
+    for field in fields.named.iter() {
+        let field_options = FieldOptions::parse(&field.attrs)?;
+        if field_options.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().unwrap();
+        if let Some(section) = field_options.section {
+            lines.push(Line::Section(section));
+        }
+
+        let label = field_options
+            .label
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        let variant = variant_name(ident);
+
+        if field_options.numeric.is_set() || is_integer(&field.ty) {
+            lines.push(Line::Numeric {
+                label,
+                field: ident,
+                variant,
+                ty: &field.ty,
+                options: Box::new(field_options.numeric),
+            });
+        } else {
+            lines.push(Line::Select {
+                label,
+                field: ident,
+                variant,
+                ty: &field.ty,
+            });
+        }
+    }
+
+    if lines.is_empty() {
+        return Err(syn::Error::new(
+            fields.span(),
+            "Menu needs at least one field that is not skipped",
+        ));
+    }
+
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+    let title = &options.title;
+    let event = &options.event;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let event_type = quote! { #event #ty_generics };
+
+    let values = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Section(_) => None,
+            Line::Select { variant, ty, .. } | Line::Numeric { variant, ty, .. } => {
+                Some((variant, *ty))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut variants = values
+        .iter()
+        .map(|(variant, ty)| quote! { #variant(#ty) })
+        .collect::<Vec<_>>();
+
+    let mut apply_branches = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Section(_) => None,
+            Line::Select { variant, field, .. } | Line::Numeric { variant, field, .. } => {
+                Some(quote! { #event::#variant(value) => self.#field = value })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut clone_branches = values
+        .iter()
+        .map(|(variant, _)| quote! { #event::#variant(value) => #event::#variant(value.clone()) })
+        .collect::<Vec<_>>();
+
+    // The event has the generics of the struct, some of which may only be used by skipped fields.
+    let phantom_params = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                Some(quote! { &#lifetime () })
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(quote! { #ident })
+            }
+            GenericParam::Const(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if !phantom_params.is_empty() {
+        variants.push(quote! {
+            #[doc(hidden)]
+            __Unused(
+                core::convert::Infallible,
+                core::marker::PhantomData<fn() -> (#(#phantom_params,)*)>,
+            )
+        });
+        apply_branches.push(quote! { #event::__Unused(never, _) => match never {} });
+        clone_branches.push(quote! { #event::__Unused(never, _) => match *never {} });
+    }
+
+    let eq_branches = values
+        .iter()
+        .map(|(variant, _)| quote! { (#event::#variant(a), #event::#variant(b)) => a == b });
+
+    // The event implements the traits that all field types implement, unlike the derives, which
+    // would require them from every type parameter. The higher-ranked bounds are accepted even if
+    // a field type never implements the trait.
+    let bounded_where_clause = |bound: TokenStream| {
+        let mut where_clause = where_clause
+            .cloned()
+            .unwrap_or_else(|| parse_quote! { where });
+        where_clause
+            .predicates
+            .extend(values.iter().map(|(_, ty)| -> WherePredicate {
+                parse_quote! { for<'__menu> #ty: #bound }
+            }));
+        where_clause
+    };
+    let clone_where_clause = bounded_where_clause(quote! { Clone });
+    let copy_where_clause = bounded_where_clause(quote! { Copy });
+    let eq_where_clause = bounded_where_clause(quote! { PartialEq });
+
+    let mut item_types = lines.iter().map(|line| line.item_type(&event_type));
+    let first = item_types.next().unwrap();
+    let items_type = item_types.fold(
+        quote! { embedded_menu::__private::Chain<#first> },
+        |parent, item| quote! { embedded_menu::__private::Link<#item, #parent> },
+    );
+
+    let add_items = lines.iter().map(|line| line.add_item(event));
+
+    let event_doc = format!("A change of a [`{struct_name}`] field, returned by its menu.");
+
+    Ok(quote! {
+        #[doc = #event_doc]
+        #vis enum #event #impl_generics #where_clause {
+            #(#variants),*
+        }
+
+        impl #impl_generics Clone for #event_type #clone_where_clause {
+            fn clone(&self) -> Self {
+                match self {
+                    #(#clone_branches),*
+                }
+            }
+        }
+
+        impl #impl_generics Copy for #event_type #copy_where_clause {}
+
+        impl #impl_generics PartialEq for #event_type #eq_where_clause {
+            #[allow(unreachable_patterns)]
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#eq_branches,)*
+                    _ => false,
+                }
+            }
+        }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Creates a menu builder with an item for each field, initialized to the current values.
+            #[allow(clippy::type_complexity)]
+            #vis fn menu_builder<IT, P, S, C, F, TF, SB>(
+                &self,
+                style: embedded_menu::MenuStyle<S, IT, P, #event_type, C, F, TF, SB>,
+            ) -> embedded_menu::builder::MenuBuilder<
+                &'static str,
+                IT,
+                #items_type,
+                #event_type,
+                P,
+                S,
+                C,
+                F,
+                TF,
+                SB,
+            >
+            where
+                IT: embedded_menu::interaction::InputAdapterSource<#event_type>,
+                P: embedded_menu::selection_indicator::SelectionIndicatorController,
+                S: embedded_menu::selection_indicator::style::IndicatorStyle,
+                C: embedded_menu::theme::Theme,
+                F: embedded_menu::MenuTextStyle<C::Color>,
+                TF: embedded_menu::MenuTextStyle<C::Color>,
+                SB: embedded_menu::scrollbar::ScrollbarStyle<C::Color>,
+            {
+                embedded_menu::builder::MenuBuilder::new(#title, style)
+                    #(#add_items)*
+            }
+
+            /// Stores a value returned by the menu in the corresponding field.
+            #vis fn apply(&mut self, event: #event_type) {
+                match event {
+                    #(#apply_branches),*
+                }
+            }
+        }
+    })
+}
@@ -4,17 +4,21 @@
 
 use embedded_graphics::{
     pixelcolor::Rgb888,
-    prelude::{DrawTargetExt, Point, RgbColor, Size},
-    primitives::Rectangle,
+    prelude::{DrawTarget, DrawTargetExt, Point, Primitive, RgbColor, Size},
+    primitives::{PrimitiveStyle, Rectangle},
+    text::renderer::TextRenderer,
     Drawable,
 };
 use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
+use embedded_layout::View;
 use embedded_menu::{
     interaction::simulator::Simulator,
-    selection_indicator::style::rectangle::Rectangle as RectangleIndicator, theme::Theme, Menu,
-    MenuStyle, SelectValue,
+    items::{Marker, MenuLine, MenuListItem},
+    selection_indicator::style::rectangle::Rectangle as RectangleIndicator,
+    theme::Theme,
+    Menu, MenuStyle, MenuTextStyle, SelectValue,
 };
 
 #[derive(Copy, Clone, PartialEq, SelectValue)]
@@ -43,6 +47,63 @@ impl Theme for ExampleTheme {
     }
 }
 
+/// A menu item that displays a color next to its title.
+struct ColorSwatch {
+    title: &'static str,
+    color: Rgb888,
+    line: MenuLine,
+}
+
+impl ColorSwatch {
+    fn new(title: &'static str, color: Rgb888) -> Self {
+        Self {
+            title,
+            color,
+            line: MenuLine::empty(),
+        }
+    }
+}
+
+impl Marker for ColorSwatch {}
+
+impl MenuListItem<(), Rgb888> for ColorSwatch {
+    fn value_of(&self) {}
+
+    fn interact(&mut self) {}
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
+        // Reserve some space for the swatch.
+        self.line = MenuLine::new("   ", text_style);
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<Rgb888>,
+        D: DrawTarget<Color = Rgb888>,
+    {
+        self.line.draw_styled(self.title, "", text_style, display)?;
+
+        let bounds = self.line.bounds();
+        let right = display.bounding_box().size.width as i32;
+        Rectangle::new(
+            Point::new(right - 12, bounds.top_left.y + 1),
+            Size::new(10, bounds.size.height.saturating_sub(2)),
+        )
+        .into_styled(PrimitiveStyle::with_fill(self.color))
+        .draw(display)
+    }
+}
+
+impl View for ColorSwatch {
+    fn translate_impl(&mut self, by: Point) {
+        self.line.translate_mut(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.line.bounds()
+    }
+}
+
 fn main() -> Result<(), core::convert::Infallible> {
     let mut menu = Menu::with_style(
         "Color Menu",
@@ -57,6 +118,8 @@ fn main() -> Result<(), core::convert::Infallible> {
     .add_item("Check this", false, |_| ())
     .add_item("Check this", TestEnum::A, |_| ())
     .add_item("Check this too", false, |_| ())
+    .add_menu_item(ColorSwatch::new("Warning", Rgb888::RED))
+    .add_menu_item(ColorSwatch::new("Info", Rgb888::BLUE))
     .build();
 
     let output_settings = OutputSettingsBuilder::new().scale(4).build();
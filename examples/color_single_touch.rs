@@ -51,6 +51,7 @@ fn main() -> Result<(), core::convert::Infallible> {
                 ignore_time: 10,
                 debounce_time: 1,
                 max_time: 100,
+                back_time: 0,
             }),
     )
     .add_item("Foo", ">", |_| ())
@@ -0,0 +1,89 @@
+//! Run using `cargo run --example derive --target x86_64-pc-windows-msvc` --features=simulator
+//!
+//! Builds a settings menu from a struct using `#[derive(Menu)]`.
+
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Size, Drawable};
+use embedded_graphics_simulator::{
+    BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+use embedded_menu::{interaction::simulator::Simulator, Menu, MenuStyle, SelectValue};
+
+#[derive(Copy, Clone, PartialEq, SelectValue)]
+pub enum Language {
+    English,
+    German,
+    Hungarian,
+}
+
+#[derive(Menu)]
+#[menu(title = "Settings")]
+struct Settings {
+    #[menu(label = "Sound")]
+    sound: bool,
+    #[menu(label = "Volume", min = 0, max = 10)]
+    volume: u8,
+    #[menu(label = "Language")]
+    language: Language,
+    #[menu(
+        section = "  Display",
+        label = "Contrast",
+        min = 0,
+        max = 100,
+        step = 5
+    )]
+    contrast: u8,
+    #[menu(label = "Temperature", min = -400, max = 1250, step = 5, decimals = 1)]
+    temperature_offset: i16,
+    #[menu(skip)]
+    #[allow(dead_code)]
+    serial_number: u32,
+}
+
+fn main() -> Result<(), core::convert::Infallible> {
+    let mut settings = Settings {
+        sound: true,
+        volume: 5,
+        language: Language::English,
+        contrast: 50,
+        temperature_offset: 0,
+        serial_number: 1234,
+    };
+
+    let output_settings = OutputSettingsBuilder::new()
+        .theme(BinaryColorTheme::OledBlue)
+        .build();
+    let mut window = Window::new("Menu demonstration", &output_settings);
+
+    let mut state = Default::default();
+
+    'running: loop {
+        // The menu is rebuilt from the settings, so the escape value is always up to date.
+        let style = MenuStyle::new(BinaryColor::On)
+            .with_input_adapter(Simulator {
+                page_size: 5,
+                esc_value: SettingsEvent::Sound(settings.sound),
+            })
+            .with_animated_selection_indicator(10);
+
+        let mut menu = settings.menu_builder(style).build_with_state(state);
+
+        let mut display = SimulatorDisplay::new(Size::new(128, 64));
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+        window.update(&display);
+
+        for event in window.events() {
+            if let SimulatorEvent::Quit = event {
+                break 'running;
+            }
+
+            if let Some(change) = menu.interact(event) {
+                settings.apply(change);
+            }
+        }
+
+        state = menu.state();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Run using `cargo run --example rotary_encoder --target x86_64-pc-windows-msvc`
+//!
+//! Emulates a rotary encoder: turn the knob using the left/right arrows, push it using the
+//! spacebar. Short press activates the selected item, long press goes back from a submenu.
+
+use embedded_graphics::{prelude::Size, Drawable};
+use embedded_graphics_simulator::{
+    sdl2::Keycode, BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent,
+    Window,
+};
+use embedded_menu::{
+    interaction::{
+        rotary_encoder::{RotaryEncoder, RotaryEncoderInput},
+        Action,
+    },
+    items::NumericItem,
+    selection_indicator::style::animated_triangle::AnimatedTriangle,
+    Menu, MenuStyle, SelectValue,
+};
+
+#[derive(Copy, Clone, PartialEq, SelectValue)]
+pub enum TestEnum {
+    A,
+    B,
+    C,
+}
+
+fn main() -> Result<(), core::convert::Infallible> {
+    let style = MenuStyle::default()
+        .with_selection_indicator(AnimatedTriangle::new(160))
+        .with_input_adapter(RotaryEncoder {
+            ignore_time: 10,
+            debounce_time: 1,
+            max_time: 100,
+            acceleration_time: 5,
+            max_acceleration: 4,
+            long_press: Action::Back,
+        })
+        .with_animated_selection_indicator(10);
+
+    let mut menu = Menu::with_style("Rotary encoder", style)
+        .add_item("Check this", false, |_| ())
+        .add_item("Check this too", TestEnum::A, |_| ())
+        .add_menu_item(NumericItem::new("Volume", 20, 0, 100).with_value_converter(|_| ()))
+        .add_submenu(
+            Menu::with_style("More items", style)
+                .add_item("Check this", true, |_| ())
+                .add_item("Check this too", TestEnum::B, |_| ())
+                .add_item("Check this", false, |_| ()),
+            (),
+        )
+        .add_item("Check this", true, |_| ())
+        .add_item("Check this too", TestEnum::A, |_| ())
+        .add_item("Check this", false, |_| ())
+        .add_item("Check this too", true, |_| ())
+        .build();
+
+    let output_settings = OutputSettingsBuilder::new()
+        .theme(BinaryColorTheme::OledBlue)
+        .build();
+    let mut window = Window::new("Menu demonstration", &output_settings);
+
+    let mut pressed = false;
+    'running: loop {
+        let mut display = SimulatorDisplay::new(Size::new(128, 64));
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+        window.update(&display);
+
+        let mut delta = 0;
+        for event in window.events() {
+            match event {
+                SimulatorEvent::KeyDown { keycode, .. } => match keycode {
+                    Keycode::Space => pressed = true,
+                    Keycode::Right => delta += 1,
+                    Keycode::Left => delta -= 1,
+                    _ => {}
+                },
+                SimulatorEvent::KeyUp {
+                    keycode: Keycode::Space,
+                    ..
+                } => pressed = false,
+                SimulatorEvent::Quit => break 'running,
+                _ => {}
+            }
+        }
+
+        menu.interact(RotaryEncoderInput { delta, pressed });
+    }
+
+    Ok(())
+}
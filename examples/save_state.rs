@@ -51,24 +51,35 @@ fn do_loop(
     for _ in 0..60 {
         let mut items = (0..item_count)
             .map(|i| {
-                MenuItem::new("Changing", data.slice_data[i]).with_value_converter(match i {
-                    0 => |data| MenuEvent::SliceCheckbox(0, data),
-                    1 => |data| MenuEvent::SliceCheckbox(1, data),
-                    2 => |data| MenuEvent::SliceCheckbox(2, data),
-                    3 => |data| MenuEvent::SliceCheckbox(3, data),
-                    4 => |data| MenuEvent::SliceCheckbox(4, data),
-                    _ => panic!(),
-                })
+                MenuItem::new("Changing", data.slice_data[i])
+                    .with_value_converter(match i {
+                        0 => |data| MenuEvent::SliceCheckbox(0, data),
+                        1 => |data| MenuEvent::SliceCheckbox(1, data),
+                        2 => |data| MenuEvent::SliceCheckbox(2, data),
+                        3 => |data| MenuEvent::SliceCheckbox(3, data),
+                        4 => |data| MenuEvent::SliceCheckbox(4, data),
+                        _ => panic!(),
+                    })
+                    // Keys let the menu keep the selected item when the number of items changes.
+                    .with_key(10 + i as u32)
             })
             .take(item_count)
             .collect::<Vec<_>>();
 
         let mut menu = Menu::with_style(&title, style)
-            .add_item("Foo", ">", |_| MenuEvent::Nothing)
+            .add_menu_item(
+                MenuItem::new("Foo", ">")
+                    .with_value_converter(|_| MenuEvent::Nothing)
+                    .with_key(0),
+            )
             .add_section_title("  Dynamic items")
             .add_menu_items(&mut items)
             .add_section_title("  Non-Dynamic")
-            .add_item("Check this too", data.select, MenuEvent::Select)
+            .add_menu_item(
+                MenuItem::new("Check this too", data.select)
+                    .with_value_converter(MenuEvent::Select)
+                    .with_key(1),
+            )
             .build_with_state(*state);
 
         let mut display = SimulatorDisplay::new(Size::new(128, 64));
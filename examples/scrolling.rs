@@ -4,7 +4,13 @@ use embedded_graphics::{pixelcolor::BinaryColor, prelude::Size, Drawable};
 use embedded_graphics_simulator::{
     BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
-use embedded_menu::{interaction::simulator::Simulator, Menu, MenuStyle, SelectValue};
+use embedded_menu::{
+    interaction::simulator::Simulator,
+    items::MenuItem,
+    marquee::{Marquee, TitleOverflow},
+    scroll::ListScrolling,
+    Menu, MenuStyle, SelectValue,
+};
 
 #[derive(Copy, Clone, PartialEq, SelectValue)]
 pub enum TestEnum {
@@ -19,7 +25,11 @@ fn main() -> Result<(), core::convert::Infallible> {
             page_size: 5,
             esc_value: (),
         })
-        .with_animated_selection_indicator(10);
+        .with_animated_selection_indicator(10)
+        .with_list_scrolling(ListScrolling::EaseOut(10))
+        .with_title_overflow(TitleOverflow::Ellipsis)
+        .with_marquee(Marquee::new())
+        .with_details_delay(60);
 
     let mut menu = Menu::with_style("Menu", style)
         .add_item("Foo", ">", |_| ())
@@ -28,10 +38,15 @@ fn main() -> Result<(), core::convert::Infallible> {
         .add_item("Check this too", TestEnum::A, |_| ())
         .add_item("Check this too", TestEnum::A, |_| ())
         .add_item("Check this", true, |_| ())
-        .add_item("Check this too", true, |_| ())
+        .add_item("This title does not fit on the display", true, |_| ())
         .add_item("Check this too", TestEnum::A, |_| ())
         .add_item("Check this", false, |_| ())
         .add_item("Check this too", true, |_| ())
+        .add_menu_item(
+            MenuItem::new("Erase all logs and restart device", ">")
+                .with_lines(2)
+                .with_detail_text("Settings are kept, measurements are lost."),
+        )
         .add_item("Foo", "<-", |_| ())
         .add_item("Check this", false, |_| ())
         .add_item("Check this too", TestEnum::A, |_| ())
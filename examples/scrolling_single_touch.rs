@@ -27,8 +27,10 @@ fn main() -> Result<(), core::convert::Infallible> {
             ignore_time: 10,
             debounce_time: 1,
             max_time: 100,
+            back_time: 0,
         })
-        .with_animated_selection_indicator(10);
+        .with_animated_selection_indicator(10)
+        .with_footer();
 
     let mut menu = Menu::with_style("Menu with even longer title", style)
         .add_item("Foo", ">", |_| ())
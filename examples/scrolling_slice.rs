@@ -5,7 +5,10 @@ use embedded_graphics_simulator::{
     BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use embedded_menu::{
-    interaction::simulator::Simulator, items::MenuItem, Menu, MenuStyle, SelectValue,
+    interaction::simulator::Simulator,
+    items::MenuItem,
+    scrollbar::{Bar, Thumb},
+    Menu, MenuStyle, SelectValue,
 };
 
 #[derive(Copy, Clone, PartialEq, SelectValue)]
@@ -21,7 +24,13 @@ fn main() -> Result<(), core::convert::Infallible> {
             page_size: 5,
             esc_value: (),
         })
-        .with_animated_selection_indicator(10);
+        .with_animated_selection_indicator(10)
+        .with_scrollbar(
+            Bar::new()
+                .with_width(5)
+                .with_track()
+                .with_thumb(Thumb::Filled),
+        );
 
     let selects1 = [
         MenuItem::new("Check this 1", false),
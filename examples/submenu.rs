@@ -0,0 +1,123 @@
+//! Run using `cargo run --example submenu --target x86_64-pc-windows-msvc` --features=simulator
+//!
+//! Navigate using up/down arrows, open submenus using the Enter key, go back using Backspace.
+//! Numeric items are edited using the up/down arrows after pressing Enter. The language is
+//! picked from a popup list.
+
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Size, Drawable};
+use embedded_graphics_simulator::{
+    BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+use embedded_menu::{
+    interaction::simulator::Simulator,
+    items::{menu_item::SelectValue, MenuItem, NumericItem, RadioGroup},
+    Menu, MenuStyle,
+};
+
+#[derive(Copy, Clone, PartialEq, embedded_menu::SelectValue)]
+pub enum Brightness {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Copy, Clone, PartialEq, embedded_menu::SelectValue)]
+pub enum Language {
+    English,
+    Deutsch,
+    Francais,
+    Espanol,
+    Italiano,
+    Nederlands,
+    Polski,
+}
+
+#[derive(Copy, Clone)]
+enum Event {
+    Nothing,
+    Inverted(bool),
+    Contrast(u8),
+    Brightness(Brightness),
+    Sound(bool),
+    Language(Language),
+    Quit,
+}
+
+fn main() -> Result<(), core::convert::Infallible> {
+    let style = MenuStyle::new(BinaryColor::On)
+        .with_input_adapter(Simulator {
+            page_size: 5,
+            esc_value: Event::Quit,
+        })
+        .with_animated_selection_indicator(10);
+
+    let mut menu = Menu::with_style("Settings", style)
+        .add_submenu(
+            Menu::with_style("Display", style)
+                .add_item("Inverted", false, Event::Inverted)
+                .add_menu_item(
+                    NumericItem::new("Contrast", 50, 0, 100)
+                        .with_step(5)
+                        .with_value_converter(Event::Contrast),
+                )
+                .add_submenu(
+                    Menu::with_style("Brightness", style).add_radio_group(
+                        RadioGroup::new(
+                            [
+                                ("Low", Brightness::Low),
+                                ("Medium", Brightness::Medium),
+                                ("High", Brightness::High),
+                            ],
+                            1,
+                        )
+                        // The ASCII font has no `•` glyph.
+                        .with_markers("(*)", "( )")
+                        .with_value_converter(Event::Brightness),
+                    ),
+                    Event::Nothing,
+                ),
+            Event::Nothing,
+        )
+        .add_submenu(
+            Menu::with_style("Sound", style).add_item("Enabled", true, Event::Sound),
+            Event::Nothing,
+        )
+        .add_menu_item(
+            MenuItem::new("Language", Language::English)
+                .with_popup_picker()
+                .with_value_converter(Event::Language),
+        )
+        .build();
+
+    let output_settings = OutputSettingsBuilder::new()
+        .theme(BinaryColorTheme::OledBlue)
+        .build();
+    let mut window = Window::new("Menu demonstration", &output_settings);
+
+    'running: loop {
+        let mut display = SimulatorDisplay::new(Size::new(128, 64));
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+        window.update(&display);
+
+        for event in window.events() {
+            match menu.interact(event) {
+                Some(Event::Inverted(inverted)) => println!("Inverted: {inverted}"),
+                Some(Event::Contrast(contrast)) => println!("Contrast: {contrast}"),
+                Some(Event::Brightness(brightness)) => {
+                    println!("Brightness: {}", brightness.marker())
+                }
+                Some(Event::Sound(enabled)) => println!("Sound: {enabled}"),
+                Some(Event::Language(language)) => println!("Language: {}", language.marker()),
+                Some(Event::Quit) => break 'running,
+                Some(Event::Nothing) | None => {}
+            }
+
+            if let SimulatorEvent::Quit = event {
+                break 'running;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! Run using `cargo run --example virtual_list --target x86_64-pc-windows-msvc` --features=simulator
+//!
+//! Displays a list of ten thousand entries. Only the visible entries are created.
+
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::Size, Drawable};
+use embedded_graphics_simulator::{
+    BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+use embedded_menu::{
+    collection::ItemSource, interaction::simulator::Simulator, items::MenuItem, Menu, MenuStyle,
+};
+
+struct LogEntries {
+    read: Vec<bool>,
+}
+
+impl ItemSource for LogEntries {
+    type Item = MenuItem<String, (), bool, true>;
+
+    fn count(&self) -> usize {
+        self.read.len()
+    }
+
+    fn item(&self, nth: usize) -> Self::Item {
+        MenuItem::new(format!("Entry {nth}"), self.read[nth])
+    }
+
+    fn update(&mut self, nth: usize, item: Self::Item) {
+        self.read[nth] = *item.value();
+    }
+}
+
+fn main() -> Result<(), core::convert::Infallible> {
+    let style = MenuStyle::new(BinaryColor::On)
+        .with_input_adapter(Simulator {
+            page_size: 5,
+            esc_value: (),
+        })
+        .with_animated_selection_indicator(10);
+
+    let mut menu = Menu::with_style("Log", style)
+        .add_virtual_items(LogEntries {
+            read: vec![false; 10_000],
+        })
+        .build();
+
+    let output_settings = OutputSettingsBuilder::new()
+        .theme(BinaryColorTheme::OledBlue)
+        .build();
+    let mut window = Window::new("Menu demonstration", &output_settings);
+
+    'running: loop {
+        let mut display = SimulatorDisplay::new(Size::new(128, 64));
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+        window.update(&display);
+
+        for event in window.events() {
+            menu.interact(event);
+
+            match event {
+                SimulatorEvent::Quit => break 'running,
+                _ => continue,
+            }
+        }
+    }
+
+    Ok(())
+}
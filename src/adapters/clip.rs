@@ -0,0 +1,65 @@
+use embedded_graphics::{
+    prelude::{Dimensions, DrawTarget},
+    primitives::{ContainsPoint, Rectangle},
+    Pixel,
+};
+
+/// Only draws pixels inside an area.
+///
+/// Unlike [`DrawTargetExt::clipped`], the overlay reports the bounding box of the parent draw
+/// target, so the layout of the drawn content does not change.
+///
+/// [`DrawTargetExt::clipped`]: embedded_graphics::draw_target::DrawTargetExt::clipped
+pub struct ClippingOverlay<'a, T, S> {
+    parent: &'a mut T,
+    area: &'a S,
+}
+
+impl<T, S> Dimensions for ClippingOverlay<'_, T, S>
+where
+    T: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+impl<T, S> DrawTarget for ClippingOverlay<'_, T, S>
+where
+    T: DrawTarget,
+    S: ContainsPoint,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.area;
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(pos, _)| area.contains(*pos)),
+        )
+    }
+}
+
+pub trait ClippingDrawTargetExt: Sized {
+    /// Only draws pixels inside `area`, without changing the bounding box of the draw target.
+    fn clip_to<'a, S>(&'a mut self, area: &'a S) -> ClippingOverlay<'a, Self, S>
+    where
+        S: ContainsPoint;
+}
+
+impl<T> ClippingDrawTargetExt for T
+where
+    T: DrawTarget,
+{
+    fn clip_to<'a, S>(&'a mut self, area: &'a S) -> ClippingOverlay<'a, Self, S>
+    where
+        S: ContainsPoint,
+    {
+        ClippingOverlay { parent: self, area }
+    }
+}
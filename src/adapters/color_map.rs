@@ -1,15 +1,17 @@
 use embedded_graphics::{
-    pixelcolor::BinaryColor,
     prelude::{Dimensions, DrawTarget, PixelColor},
     primitives::{ContainsPoint, Rectangle},
     Pixel,
 };
 
+/// Replaces a color with another one inside an area.
+///
+/// Pixels outside the area, and pixels of any other color, are drawn unchanged.
 pub struct ColorMappingOverlay<'a, T, S, C> {
     parent: &'a mut T,
     area: S,
-    on_color: C,
-    off_color: C,
+    from: C,
+    to: C,
 }
 
 impl<T, S, C> Dimensions for ColorMappingOverlay<'_, T, S, C>
@@ -27,19 +29,19 @@ where
     S: ContainsPoint,
     C: PixelColor,
 {
-    type Color = BinaryColor;
+    type Color = C;
     type Error = T::Error;
 
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
-        I: IntoIterator<Item = Pixel<BinaryColor>>,
+        I: IntoIterator<Item = Pixel<C>>,
     {
         self.parent
             .draw_iter(pixels.into_iter().map(|Pixel(pos, color)| {
-                let color = if self.area.contains(pos) ^ (color == BinaryColor::On) {
-                    self.on_color
+                let color = if color == self.from && self.area.contains(pos) {
+                    self.to
                 } else {
-                    self.off_color
+                    color
                 };
 
                 Pixel(pos, color)
@@ -47,27 +49,66 @@ where
     }
 }
 
-pub trait BinaryColorDrawTargetExt: Sized {
-    fn map_colors<S, C>(&mut self, area: &S, on: C, off: C) -> ColorMappingOverlay<'_, Self, S, C>
+pub trait ColorMappingDrawTargetExt: DrawTarget + Sized {
+    /// Draws pixels of the `from` color using the `to` color inside `area`.
+    fn map_color<S>(
+        &mut self,
+        area: &S,
+        from: Self::Color,
+        to: Self::Color,
+    ) -> ColorMappingOverlay<'_, Self, S, Self::Color>
     where
-        S: Clone + ContainsPoint,
-        C: PixelColor;
+        S: Clone + ContainsPoint;
 }
 
-impl<T> BinaryColorDrawTargetExt for T
+impl<T> ColorMappingDrawTargetExt for T
 where
     T: DrawTarget,
 {
-    fn map_colors<S, C>(&mut self, area: &S, on: C, off: C) -> ColorMappingOverlay<'_, Self, S, C>
+    fn map_color<S>(
+        &mut self,
+        area: &S,
+        from: Self::Color,
+        to: Self::Color,
+    ) -> ColorMappingOverlay<'_, Self, S, Self::Color>
     where
         S: Clone + ContainsPoint,
-        C: PixelColor,
     {
         ColorMappingOverlay {
             parent: self,
             area: area.clone(),
-            on_color: on,
-            off_color: off,
+            from,
+            to,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Point, Size},
+        primitives::Rectangle,
+        Pixel,
+    };
+
+    use super::*;
+
+    #[test]
+    fn maps_color_inside_area_only() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let area = Rectangle::new(Point::new(1, 0), Size::new(1, 1));
+
+        display
+            .map_color(&area, BinaryColor::On, BinaryColor::Off)
+            .draw_iter([
+                Pixel(Point::new(0, 0), BinaryColor::On),
+                Pixel(Point::new(1, 0), BinaryColor::On),
+                Pixel(Point::new(2, 0), BinaryColor::Off),
+            ])
+            .unwrap();
+
+        display.assert_pattern(&["#..", "   "]);
+    }
+}
@@ -0,0 +1,162 @@
+use core::fmt::{self, Write};
+
+/// Fixed capacity text buffer, used to format and edit text without allocating.
+#[derive(Clone, Copy)]
+pub struct TextBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for TextBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Bytes past `len` may be left over from removed characters, so only compare the text.
+impl<const N: usize> PartialEq for TextBuffer<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for TextBuffer<N> {}
+
+impl<const N: usize> fmt::Debug for TextBuffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> TextBuffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Copies `text` into a new buffer. Characters that don't fit are dropped.
+    pub fn from_str_truncated(text: &str) -> Self {
+        let mut buffer = Self::new();
+        for c in text.chars() {
+            if !buffer.insert(buffer.char_count(), c) {
+                break;
+            }
+        }
+        buffer
+    }
+
+    /// Formats `args` into a new buffer. Text that doesn't fit is dropped.
+    pub fn format(args: fmt::Arguments<'_>) -> Self {
+        let mut buffer = Self::new();
+        _ = buffer.write_fmt(args);
+        buffer
+    }
+
+    pub fn as_str(&self) -> &str {
+        // We only ever store complete `str`s, so this can't fail.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+
+    /// Returns the number of characters in the buffer.
+    pub fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Inserts `c` before the `idx`th character. Returns `false` if the character doesn't fit.
+    pub fn insert(&mut self, idx: usize, c: char) -> bool {
+        let mut encoded = [0; 4];
+        let encoded = c.encode_utf8(&mut encoded).as_bytes();
+
+        let end = self.len + encoded.len();
+        if end > N {
+            return false;
+        }
+
+        let offset = self.byte_offset(idx);
+        self.bytes
+            .copy_within(offset..self.len, offset + encoded.len());
+        self.bytes[offset..offset + encoded.len()].copy_from_slice(encoded);
+        self.len = end;
+
+        true
+    }
+
+    /// Removes and returns the `idx`th character.
+    pub fn remove(&mut self, idx: usize) -> Option<char> {
+        let offset = self.byte_offset(idx);
+        let c = self.as_str()[offset..].chars().next()?;
+
+        self.bytes
+            .copy_within(offset + c.len_utf8()..self.len, offset);
+        self.len -= c.len_utf8();
+
+        Some(c)
+    }
+
+    /// Replaces the `idx`th character with `c`. Returns `false` if the character doesn't fit.
+    pub fn replace(&mut self, idx: usize, c: char) -> bool {
+        let Some(old) = self.remove(idx) else {
+            return false;
+        };
+
+        if self.insert(idx, c) {
+            true
+        } else {
+            self.insert(idx, old);
+            false
+        }
+    }
+
+    fn byte_offset(&self, idx: usize) -> usize {
+        self.as_str()
+            .char_indices()
+            .nth(idx)
+            .map_or(self.len, |(offset, _)| offset)
+    }
+}
+
+impl<const N: usize> Write for TextBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn edits_multibyte_characters() {
+        let mut buffer = TextBuffer::<6>::from_str_truncated("aéb");
+        assert_eq!(buffer.char_count(), 3);
+
+        assert!(buffer.insert(1, 'ő'));
+        assert_eq!(buffer.as_str(), "aőéb");
+
+        assert_eq!(buffer.remove(2), Some('é'));
+        assert!(buffer.replace(0, 'x'));
+        assert_eq!(buffer.as_str(), "xőb");
+
+        assert!(buffer.insert(3, 'c'));
+        assert!(!buffer.insert(0, 'é'));
+        assert_eq!(buffer.as_str(), "xőbc");
+    }
+
+    #[test]
+    fn equality_ignores_removed_characters() {
+        let mut buffer = TextBuffer::<4>::from_str_truncated("abc");
+        assert_eq!(buffer.remove(2), Some('c'));
+
+        assert_eq!(buffer, TextBuffer::from_str_truncated("ab"));
+    }
+}
@@ -1,12 +1,17 @@
 use crate::{
-    collection::{MenuItemCollection, MenuItems},
-    interaction::{InputAdapterSource, InputState},
-    items::{menu_item::SelectValue, MenuItem, MenuListItem},
+    buffer::TextBuffer,
+    collection::{ItemSource, LevelVisitor, MenuItemCollection, MenuItems, VirtualItems},
+    interaction::{InputAdapterSource, InputState, Navigation},
+    is_valid_path,
+    items::{menu_item::SelectValue, MenuItem, MenuListItem, RadioGroup, SubMenu},
+    navigation::NavigationStack,
+    scrollbar::ScrollbarStyle,
     selection_indicator::{style::IndicatorStyle, SelectionIndicatorController},
     theme::Theme,
-    Menu, MenuState, MenuStyle, NoItems,
+    visit_level, ChangedItems, ItemCount, Menu, MenuState, MenuStyle, MenuTextStyle, NoItems,
 };
 use core::marker::PhantomData;
+use embedded_graphics::pixelcolor::PixelColor;
 use embedded_layout::{
     layout::linear::LinearLayout,
     object_chain::ChainElement,
@@ -14,29 +19,35 @@ use embedded_layout::{
     view_group::{EmptyViewGroup, ViewGroup},
 };
 
-pub struct MenuBuilder<T, IT, LL, R, P, S, C>
+pub struct MenuBuilder<T, IT, LL, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
     S: IndicatorStyle,
     P: SelectionIndicatorController,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     title: T,
     items: LL,
-    style: MenuStyle<S, IT, P, R, C>,
+    style: MenuStyle<S, IT, P, R, C, F, TF, SB>,
 }
 
-impl<T, R, S, IT, P, C> MenuBuilder<T, IT, NoItems, R, P, S, C>
+impl<T, R, S, IT, P, C, F, TF, SB> MenuBuilder<T, IT, NoItems, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     S: IndicatorStyle,
     IT: InputAdapterSource<R>,
     P: SelectionIndicatorController,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Creates a new menu builder with the given title and style.
-    pub const fn new(title: T, style: MenuStyle<S, IT, P, R, C>) -> Self {
+    pub const fn new(title: T, style: MenuStyle<S, IT, P, R, C, F, TF, SB>) -> Self {
         Self {
             title,
             items: NoItems,
@@ -45,19 +56,23 @@ where
     }
 }
 
-impl<T, IT, R, P, S, C> MenuBuilder<T, IT, NoItems, R, P, S, C>
+#[allow(clippy::type_complexity)]
+impl<T, IT, R, P, S, C, F, TF, SB> MenuBuilder<T, IT, NoItems, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Append a non-selectable menu item to the menu with the given title.
     pub fn add_section_title<T2: AsRef<str>>(
         self,
         title: T2,
-    ) -> MenuBuilder<T, IT, Chain<MenuItem<T2, R, (), false>>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Chain<MenuItem<T2, R, (), false>>, R, P, S, C, F, TF, SB> {
         self.add_menu_item(
             MenuItem::new(title, ())
                 .with_value_converter(|_| unreachable!())
@@ -75,15 +90,39 @@ where
         title: T2,
         value: V,
         converter: fn(V) -> R,
-    ) -> MenuBuilder<T, IT, Chain<MenuItem<T2, R, V, true>>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Chain<MenuItem<T2, R, V, true>>, R, P, S, C, F, TF, SB> {
         self.add_menu_item(MenuItem::new(title, value).with_value_converter(converter))
     }
 
+    /// Append a submenu to the menu.
+    ///
+    /// The submenu's title and items are taken from the `submenu` builder. Selecting the item
+    /// opens the submenu, [`Action::Back`] returns to this menu. `value` is returned by
+    /// [`Menu::selected_value`] while the submenu item is selected.
+    ///
+    /// The submenu is drawn using the style of this menu, and the style of the `submenu` builder
+    /// is discarded. Its items are laid out using the text style of the `submenu` builder though,
+    /// so both builders should be created with the same text style.
+    ///
+    /// [`Action::Back`]: crate::interaction::Action::Back
+    pub fn add_submenu<T2, CE2>(
+        self,
+        submenu: MenuBuilder<T2, IT, CE2, R, P, S, C, F, TF, SB>,
+        value: R,
+    ) -> MenuBuilder<T, IT, Chain<SubMenu<T2, CE2, R>>, R, P, S, C, F, TF, SB>
+    where
+        T2: AsRef<str>,
+        CE2: ViewGroup + MenuItemCollection<R, C::Color>,
+        R: Clone,
+    {
+        self.add_menu_item(submenu.into_submenu(value))
+    }
+
     /// Append an arbitrary [`MenuListItem`] implementation to the menu.
-    pub fn add_menu_item<I: MenuListItem<R>>(
+    pub fn add_menu_item<I: MenuListItem<R, C::Color>>(
         self,
         mut item: I,
-    ) -> MenuBuilder<T, IT, Chain<I>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Chain<I>, R, P, S, C, F, TF, SB> {
         item.set_style(&self.style.text_style());
 
         MenuBuilder {
@@ -97,9 +136,9 @@ where
     pub fn add_menu_items<I, IC>(
         self,
         mut items: IC,
-    ) -> MenuBuilder<T, IT, Chain<MenuItems<IC, I, R>>, R, P, S, C>
+    ) -> MenuBuilder<T, IT, Chain<MenuItems<IC, I, R>>, R, P, S, C, F, TF, SB>
     where
-        I: MenuListItem<R>,
+        I: MenuListItem<R, C::Color>,
         IC: AsRef<[I]> + AsMut<[I]>,
     {
         items
@@ -113,22 +152,65 @@ where
             style: self.style,
         }
     }
+
+    /// Append a group of options of which exactly one is active. Each option is displayed as its
+    /// own row.
+    ///
+    /// See [`RadioGroup`] for details.
+    pub fn add_radio_group<T2, V, const N: usize>(
+        self,
+        mut group: RadioGroup<T2, V, R, N>,
+    ) -> MenuBuilder<T, IT, Chain<RadioGroup<T2, V, R, N>>, R, P, S, C, F, TF, SB>
+    where
+        T2: AsRef<str>,
+        V: Clone,
+    {
+        group.set_style::<C::Color, _>(&self.style.text_style());
+
+        MenuBuilder {
+            title: self.title,
+            items: Chain::new(group),
+            style: self.style,
+        }
+    }
+
+    /// Append a collection of items that are created by `source` when needed.
+    ///
+    /// See [`VirtualItems`] for details.
+    pub fn add_virtual_items<IS>(
+        self,
+        source: IS,
+    ) -> MenuBuilder<T, IT, Chain<VirtualItems<IS, F>>, R, P, S, C, F, TF, SB>
+    where
+        IS: ItemSource,
+        IS::Item: MenuListItem<R, C::Color>,
+    {
+        MenuBuilder {
+            title: self.title,
+            items: Chain::new(VirtualItems::new(source, self.style.text_style())),
+            style: self.style,
+        }
+    }
 }
 
-impl<T, IT, CE, R, P, S, C> MenuBuilder<T, IT, CE, R, P, S, C>
+#[allow(clippy::type_complexity)]
+impl<T, IT, CE, R, P, S, C, F, TF, SB> MenuBuilder<T, IT, CE, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
-    CE: MenuItemCollection<R> + ChainElement,
+    CE: MenuItemCollection<R, C::Color> + ChainElement,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Append a non-selectable menu item to the menu with the given title.
     pub fn add_section_title<T2: AsRef<str>>(
         self,
         title: T2,
-    ) -> MenuBuilder<T, IT, Link<MenuItem<T2, R, (), false>, CE>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Link<MenuItem<T2, R, (), false>, CE>, R, P, S, C, F, TF, SB> {
         self.add_menu_item(
             MenuItem::new(title, ())
                 .with_value_converter(|_| unreachable!())
@@ -146,15 +228,39 @@ where
         title: T2,
         value: V,
         converter: fn(V) -> R,
-    ) -> MenuBuilder<T, IT, Link<MenuItem<T2, R, V, true>, CE>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Link<MenuItem<T2, R, V, true>, CE>, R, P, S, C, F, TF, SB> {
         self.add_menu_item(MenuItem::new(title, value).with_value_converter(converter))
     }
 
+    /// Append a submenu to the menu.
+    ///
+    /// The submenu's title and items are taken from the `submenu` builder. Selecting the item
+    /// opens the submenu, [`Action::Back`] returns to this menu. `value` is returned by
+    /// [`Menu::selected_value`] while the submenu item is selected.
+    ///
+    /// The submenu is drawn using the style of this menu, and the style of the `submenu` builder
+    /// is discarded. Its items are laid out using the text style of the `submenu` builder though,
+    /// so both builders should be created with the same text style.
+    ///
+    /// [`Action::Back`]: crate::interaction::Action::Back
+    pub fn add_submenu<T2, CE2>(
+        self,
+        submenu: MenuBuilder<T2, IT, CE2, R, P, S, C, F, TF, SB>,
+        value: R,
+    ) -> MenuBuilder<T, IT, Link<SubMenu<T2, CE2, R>, CE>, R, P, S, C, F, TF, SB>
+    where
+        T2: AsRef<str>,
+        CE2: ViewGroup + MenuItemCollection<R, C::Color>,
+        R: Clone,
+    {
+        self.add_menu_item(submenu.into_submenu(value))
+    }
+
     /// Append an arbitrary [`MenuListItem`] implementation to the menu.
-    pub fn add_menu_item<I: MenuListItem<R>>(
+    pub fn add_menu_item<I: MenuListItem<R, C::Color>>(
         self,
         mut item: I,
-    ) -> MenuBuilder<T, IT, Link<I, CE>, R, P, S, C> {
+    ) -> MenuBuilder<T, IT, Link<I, CE>, R, P, S, C, F, TF, SB> {
         item.set_style(&self.style.text_style());
 
         MenuBuilder {
@@ -171,9 +277,9 @@ where
     pub fn add_menu_items<I, IC>(
         self,
         mut items: IC,
-    ) -> MenuBuilder<T, IT, Link<MenuItems<IC, I, R>, CE>, R, P, S, C>
+    ) -> MenuBuilder<T, IT, Link<MenuItems<IC, I, R>, CE>, R, P, S, C, F, TF, SB>
     where
-        I: MenuListItem<R>,
+        I: MenuListItem<R, C::Color>,
         IC: AsRef<[I]> + AsMut<[I]>,
     {
         items
@@ -190,25 +296,77 @@ where
             style: self.style,
         }
     }
+
+    /// Append a group of options of which exactly one is active. Each option is displayed as its
+    /// own row.
+    ///
+    /// See [`RadioGroup`] for details.
+    pub fn add_radio_group<T2, V, const N: usize>(
+        self,
+        mut group: RadioGroup<T2, V, R, N>,
+    ) -> MenuBuilder<T, IT, Link<RadioGroup<T2, V, R, N>, CE>, R, P, S, C, F, TF, SB>
+    where
+        T2: AsRef<str>,
+        V: Clone,
+    {
+        group.set_style::<C::Color, _>(&self.style.text_style());
+
+        MenuBuilder {
+            title: self.title,
+            items: Link {
+                parent: self.items,
+                object: group,
+            },
+            style: self.style,
+        }
+    }
+
+    /// Append a collection of items that are created by `source` when needed.
+    ///
+    /// See [`VirtualItems`] for details.
+    pub fn add_virtual_items<IS>(
+        self,
+        source: IS,
+    ) -> MenuBuilder<T, IT, Link<VirtualItems<IS, F>, CE>, R, P, S, C, F, TF, SB>
+    where
+        IS: ItemSource,
+        IS::Item: MenuListItem<R, C::Color>,
+    {
+        MenuBuilder {
+            title: self.title,
+            items: Link {
+                parent: self.items,
+                object: VirtualItems::new(source, self.style.text_style()),
+            },
+            style: self.style,
+        }
+    }
 }
 
-impl<T, IT, VG, R, P, S, C> MenuBuilder<T, IT, VG, R, P, S, C>
+impl<T, IT, VG, R, P, S, C, F, TF, SB> MenuBuilder<T, IT, VG, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
-    VG: ViewGroup + MenuItemCollection<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Builds the menu and initializes it to a default state.
-    pub fn build(self) -> Menu<T, IT, VG, R, P, S, C> {
+    pub fn build(self) -> Menu<T, IT, VG, R, P, S, C, F, TF, SB> {
         self.build_with_state(MenuState {
             selected: 0,
+            selected_key: None,
             list_offset: 0,
             interaction_state: Default::default(),
             indicator_state: Default::default(),
             last_input_state: InputState::Idle,
+            navigation: NavigationStack::new(),
+            selection_ticks: 0,
+            scroll_carry_ms: 0,
         })
     }
 
@@ -216,14 +374,64 @@ where
     pub fn build_with_state(
         mut self,
         mut state: MenuState<IT::InputAdapter, P, S>,
-    ) -> Menu<T, IT, VG, R, P, S, C> {
-        // We have less menu items than before. Avoid crashing.
-        let max_idx = self.items.count().saturating_sub(1);
-
+    ) -> Menu<T, IT, VG, R, P, S, C, F, TF, SB> {
         LinearLayout::vertical(EmptyViewGroup).arrange_view_group(&mut self.items);
 
-        state.set_selected_item(state.selected, &self.items, &self.style);
-        if max_idx < state.selected {
+        // Submenus may have been moved or removed since the state was saved. Find the opened
+        // submenus by their keys, and return to the closest parent that still exists.
+        let title = self.title.as_ref();
+        let items = &self.items;
+        let resolved = state.navigation.resolve(|path, key, hint| {
+            if is_valid_path(items, path) {
+                visit_level(title, items, path, FindKey { key, hint })
+            } else {
+                None
+            }
+        });
+        while state.navigation.depth() > resolved
+            || !is_valid_path(&self.items, state.navigation.path())
+        {
+            let (selected, key, list_offset) = state.navigation.pop().unwrap();
+            state.selected = selected;
+            state.selected_key = key;
+            state.list_offset = list_offset;
+        }
+
+        // Items may have been inserted or removed since the state was saved. Select the item with
+        // the remembered key, wherever it is now.
+        let mut moved = false;
+        if let Some(key) = state.selected_key {
+            let position = visit_level(
+                self.title.as_ref(),
+                &self.items,
+                state.navigation.path(),
+                FindKey {
+                    key,
+                    hint: state.selected,
+                },
+            );
+
+            if let Some(position) = position {
+                moved = position != state.selected;
+                state.selected = position;
+            }
+        }
+
+        // We have less menu items than before. Avoid crashing.
+        let item_count = visit_level(
+            self.title.as_ref(),
+            &self.items,
+            state.navigation.path(),
+            ItemCount,
+        );
+
+        state.select_item(
+            Navigation::JumpTo(state.selected),
+            self.title.as_ref(),
+            &self.items,
+            &self.style,
+        );
+        if moved || state.selected >= item_count {
             self.style
                 .indicator
                 .jump_to_target(&mut state.indicator_state);
@@ -235,6 +443,132 @@ where
             title: self.title,
             items: self.items,
             style: self.style,
+            last_drawn: None,
+            changed_items: ChangedItems::None,
+            status: TextBuffer::new(),
+            dialog: None,
+            message_box: None,
+            toast: None,
         }
     }
+
+    fn into_submenu(mut self, value: R) -> SubMenu<T, VG, R> {
+        LinearLayout::vertical(EmptyViewGroup).arrange_view_group(&mut self.items);
+
+        SubMenu::new(self.title, self.items, value)
+    }
+}
+
+/// Returns the position of the item with the given key, checking `hint` first.
+struct FindKey {
+    key: u32,
+    hint: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for FindKey {
+    type Output = Option<usize>;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        let count = items.count();
+        if self.hint < count && items.key_of(self.hint) == Some(self.key) {
+            return Some(self.hint);
+        }
+
+        (0..count).find(|&nth| items.key_of(nth) == Some(self.key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        Menu,
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    #[test]
+    fn state_restores_selection_by_key() {
+        use crate::items::MenuItem;
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_menu_item(
+                MenuItem::new("A", ())
+                    .with_value_converter(|_| 'a')
+                    .with_key(1),
+            )
+            .add_menu_item(
+                MenuItem::new("B", ())
+                    .with_value_converter(|_| 'b')
+                    .with_key(2),
+            )
+            .add_menu_item(
+                MenuItem::new("C", ())
+                    .with_value_converter(|_| 'c')
+                    .with_key(3),
+            )
+            .build();
+
+        menu.interact(Interaction::Navigation(Navigation::End));
+        assert_eq!(menu.state().selected_key(), Some(3));
+
+        // Insert an item before the selected one and remove another one.
+        let menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_item("New 1", (), |_| '1')
+            .add_item("New 2", (), |_| '2')
+            .add_menu_item(
+                MenuItem::new("C", ())
+                    .with_value_converter(|_| 'c')
+                    .with_key(3),
+            )
+            .add_menu_item(
+                MenuItem::new("A", ())
+                    .with_value_converter(|_| 'a')
+                    .with_key(1),
+            )
+            .build_with_state(menu.state());
+
+        assert_eq!(menu.selected_value(), 'c');
+    }
+
+    #[test]
+    fn state_restores_open_submenu_by_key() {
+        use crate::items::{MenuItem, SubMenu};
+        use embedded_layout::object_chain::Chain;
+
+        let submenu = || {
+            SubMenu::new(
+                "Settings",
+                Chain::new(MenuItem::new("Inner", ()).with_value_converter(|_| 'i')),
+                's',
+            )
+            .with_key(7)
+        };
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_item("A", (), |_| 'a')
+            .add_menu_item(submenu())
+            .build();
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.interact(Interaction::Action(Action::Select));
+        assert_eq!(menu.state().depth(), 1);
+        assert_eq!(menu.selected_value(), 'i');
+
+        // Insert an item above the open submenu.
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_item("New", (), |_| 'n')
+            .add_item("A", (), |_| 'a')
+            .add_menu_item(submenu())
+            .build_with_state(menu.state());
+
+        assert_eq!(menu.state().depth(), 1);
+        assert_eq!(menu.selected_value(), 'i');
+
+        menu.interact(Interaction::Action(Action::Back));
+        assert_eq!(menu.state().depth(), 0);
+        assert_eq!(menu.selected_value(), 's');
+    }
 }
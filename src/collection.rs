@@ -1,36 +1,140 @@
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::Range};
 
 use embedded_graphics::{
-    mono_font::MonoTextStyle,
-    pixelcolor::BinaryColor,
-    prelude::{DrawTarget, Point, Size},
+    prelude::{DrawTarget, PixelColor, Point, Size},
     primitives::Rectangle,
+    text::renderer::TextRenderer,
 };
 use embedded_layout::{object_chain::ChainElement, prelude::*, view_group::ViewGroup};
 
-use crate::items::{Marker, MenuListItem};
+use crate::{
+    dialog::ConfirmDialog,
+    items::{DrawContext, EditAction, Marker, MenuListItem},
+    marquee::Marquee,
+    MenuTextStyle,
+};
+
+/// An operation that runs on the items of a single menu level.
+pub trait LevelVisitor<R, C: PixelColor> {
+    type Output;
+
+    fn visit<L>(self, title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View;
+}
+
+/// An operation that runs on the items of a single menu level, and may modify them.
+pub trait LevelVisitorMut<R, C: PixelColor> {
+    type Output;
+
+    fn visit<L>(self, title: &str, items: &mut L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View;
+}
 
 /// Menu-related extensions for object chain elements
-pub trait MenuItemCollection<R> {
+pub trait MenuItemCollection<R, C: PixelColor> {
     fn bounds_of(&self, nth: usize) -> Rectangle;
     fn value_of(&self, nth: usize) -> R;
     fn interact_with(&mut self, nth: usize) -> R;
     /// Whether an item is selectable. If not, the item will be skipped.
     fn selectable(&self, nth: usize) -> bool;
+    /// The key that identifies an item. See [`MenuListItem::key`].
+    fn key_of(&self, _nth: usize) -> Option<u32> {
+        None
+    }
+    /// The hint displayed in the footer while an item is selected. See [`MenuListItem::hint`].
+    fn hint_of(&self, _nth: usize) -> Option<&'static str> {
+        None
+    }
+    /// The description of an item. See [`MenuListItem::details`].
+    fn details_of(&self, _nth: usize) -> Option<&'static str> {
+        None
+    }
+    /// The dialog that confirms the interaction with an item. See
+    /// [`MenuListItem::confirmation`].
+    fn confirmation_of(&self, _nth: usize) -> Option<ConfirmDialog> {
+        None
+    }
+    /// The items whose appearance may change when the user interacts with the `nth` item,
+    /// including the item itself, e.g. the options of a [`RadioGroup`].
+    ///
+    /// [`RadioGroup`]: crate::items::RadioGroup
+    fn linked_items(&self, nth: usize) -> Range<usize> {
+        nth..nth + 1
+    }
+    /// Whether an item is being edited. See [`MenuListItem::is_editing`].
+    fn is_editing(&self, _nth: usize) -> bool {
+        false
+    }
+    /// Lets the item enter editing mode. See [`MenuListItem::begin_edit`].
+    fn begin_edit(&mut self, _nth: usize) -> bool {
+        false
+    }
+    /// Forwards user input to an item that is being edited. See [`MenuListItem::edit`].
+    fn edit(&mut self, _nth: usize, _action: EditAction) -> Option<R> {
+        None
+    }
+    /// Lists the options of the popup picker opened by an item. See [`MenuListItem::picker`].
+    fn picker_of(&self, _nth: usize, _option: &mut dyn FnMut(&str, bool)) -> bool {
+        false
+    }
+    /// The number of pixels the text scrolled by `marquee` is wider than its area. See
+    /// [`MenuListItem::marquee_overflow`].
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        _nth: usize,
+        _text_style: &F,
+        _marquee: &Marquee,
+        _width: u32,
+    ) -> u32 {
+        0
+    }
     fn count(&self) -> usize;
-    fn draw_styled<D>(
+    /// Draws the items. Item `n` is drawn using `context.nth(n)`.
+    fn draw_styled<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>;
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>;
+
+    /// Runs `visitor` on the submenu opened by the `nth` item. The remaining elements of `path`
+    /// select further nested submenus.
+    ///
+    /// Returns `None` if the path does not lead to a submenu.
+    fn visit_submenu<V>(&self, _nth: usize, _path: &[usize], _visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        None
+    }
+
+    /// Runs `visitor` on the submenu opened by the `nth` item. The remaining elements of `path`
+    /// select further nested submenus.
+    ///
+    /// Returns `None` if the path does not lead to a submenu.
+    fn visit_submenu_mut<V>(
+        &mut self,
+        _nth: usize,
+        _path: &[usize],
+        _visitor: V,
+    ) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        None
+    }
 }
 
 // Treat any MenuItem impl as a 1-element collection
-impl<I, R> MenuItemCollection<R> for I
+impl<I, R, C> MenuItemCollection<R, C> for I
 where
-    I: MenuListItem<R> + Marker,
+    I: MenuListItem<R, C> + Marker,
+    C: PixelColor,
 {
     fn bounds_of(&self, nth: usize) -> Rectangle {
         debug_assert!(nth == 0);
@@ -52,39 +156,108 @@ where
         self.selectable()
     }
 
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        debug_assert!(nth == 0);
+        self.key()
+    }
+
+    fn hint_of(&self, nth: usize) -> Option<&'static str> {
+        debug_assert!(nth == 0);
+        self.hint()
+    }
+
+    fn details_of(&self, nth: usize) -> Option<&'static str> {
+        debug_assert!(nth == 0);
+        self.details()
+    }
+
+    fn confirmation_of(&self, nth: usize) -> Option<ConfirmDialog> {
+        debug_assert!(nth == 0);
+        self.confirmation()
+    }
+
+    fn is_editing(&self, nth: usize) -> bool {
+        debug_assert!(nth == 0);
+        MenuListItem::is_editing(self)
+    }
+
+    fn begin_edit(&mut self, nth: usize) -> bool {
+        debug_assert!(nth == 0);
+        MenuListItem::begin_edit(self)
+    }
+
+    fn edit(&mut self, nth: usize, action: EditAction) -> Option<R> {
+        debug_assert!(nth == 0);
+        MenuListItem::edit(self, action)
+    }
+
+    fn picker_of(&self, nth: usize, option: &mut dyn FnMut(&str, bool)) -> bool {
+        debug_assert!(nth == 0);
+        MenuListItem::picker(self, option)
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        debug_assert!(nth == 0);
+        MenuListItem::marquee_overflow(self, text_style, marquee, width)
+    }
+
     fn count(&self) -> usize {
         1
     }
 
-    fn draw_styled<D>(
+    fn draw_styled<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        MenuListItem::draw_with_context(self, text_style, context, display)
+    }
+
+    fn visit_submenu<V>(&self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
     {
-        MenuListItem::draw_styled(self, text_style, display)
+        debug_assert!(nth == 0);
+        MenuListItem::visit_submenu(self, path, visitor)
+    }
+
+    fn visit_submenu_mut<V>(&mut self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        debug_assert!(nth == 0);
+        MenuListItem::visit_submenu_mut(self, path, visitor)
     }
 }
 
-pub struct MenuItems<C, I, R>
+pub struct MenuItems<IC, I, R>
 where
-    C: AsRef<[I]> + AsMut<[I]>,
-    I: MenuListItem<R>,
+    IC: AsRef<[I]> + AsMut<[I]>,
+    I: View,
 {
-    items: C,
+    items: IC,
     /// Used to keep track of the whole collection's position in case it's empty.
     position: Point,
     _marker: PhantomData<(I, R)>,
 }
 
-impl<C, I, R> MenuItems<C, I, R>
+impl<IC, I, R> MenuItems<IC, I, R>
 where
-    C: AsRef<[I]> + AsMut<[I]>,
-    I: MenuListItem<R>,
+    IC: AsRef<[I]> + AsMut<[I]>,
+    I: View,
 {
-    pub fn new(mut items: C) -> Self {
+    pub fn new(mut items: IC) -> Self {
         let mut offset = 0;
 
         for item in items.as_mut().iter_mut() {
@@ -100,10 +273,11 @@ where
     }
 }
 
-impl<C, I, R> MenuItemCollection<R> for MenuItems<C, I, R>
+impl<IC, I, R, C> MenuItemCollection<R, C> for MenuItems<IC, I, R>
 where
-    C: AsRef<[I]> + AsMut<[I]>,
-    I: MenuListItem<R>,
+    IC: AsRef<[I]> + AsMut<[I]>,
+    I: MenuListItem<R, C>,
+    C: PixelColor,
 {
     fn bounds_of(&self, nth: usize) -> Rectangle {
         self.items.as_ref()[nth].bounds()
@@ -121,30 +295,88 @@ where
         self.items.as_ref()[nth].selectable()
     }
 
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        self.items.as_ref()[nth].key()
+    }
+
+    fn hint_of(&self, nth: usize) -> Option<&'static str> {
+        self.items.as_ref()[nth].hint()
+    }
+
+    fn details_of(&self, nth: usize) -> Option<&'static str> {
+        self.items.as_ref()[nth].details()
+    }
+
+    fn confirmation_of(&self, nth: usize) -> Option<ConfirmDialog> {
+        self.items.as_ref()[nth].confirmation()
+    }
+
+    fn is_editing(&self, nth: usize) -> bool {
+        self.items.as_ref()[nth].is_editing()
+    }
+
+    fn begin_edit(&mut self, nth: usize) -> bool {
+        self.items.as_mut()[nth].begin_edit()
+    }
+
+    fn edit(&mut self, nth: usize, action: EditAction) -> Option<R> {
+        self.items.as_mut()[nth].edit(action)
+    }
+
+    fn picker_of(&self, nth: usize, option: &mut dyn FnMut(&str, bool)) -> bool {
+        self.items.as_ref()[nth].picker(option)
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.items.as_ref()[nth].marquee_overflow(text_style, marquee, width)
+    }
+
     fn count(&self) -> usize {
         self.items.as_ref().len()
     }
 
-    fn draw_styled<D>(
+    fn draw_styled<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
     {
-        for item in self.items.as_ref() {
-            item.draw_styled(text_style, display)?;
+        for (nth, item) in self.items.as_ref().iter().enumerate() {
+            item.draw_with_context(text_style, &context.nth(nth), display)?;
         }
 
         Ok(())
     }
+
+    fn visit_submenu<V>(&self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        self.items.as_ref()[nth].visit_submenu(path, visitor)
+    }
+
+    fn visit_submenu_mut<V>(&mut self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        self.items.as_mut()[nth].visit_submenu_mut(path, visitor)
+    }
 }
 
-impl<C, I, R> View for MenuItems<C, I, R>
+impl<IC, I, R> View for MenuItems<IC, I, R>
 where
-    C: AsRef<[I]> + AsMut<[I]>,
-    I: MenuListItem<R>,
+    IC: AsRef<[I]> + AsMut<[I]>,
+    I: View,
 {
     fn translate_impl(&mut self, by: Point) {
         self.position += by;
@@ -168,13 +400,13 @@ where
     }
 }
 
-impl<C, I, R> ViewGroup for MenuItems<C, I, R>
+impl<IC, I, R> ViewGroup for MenuItems<IC, I, R>
 where
-    C: AsRef<[I]> + AsMut<[I]>,
-    I: MenuListItem<R>,
+    IC: AsRef<[I]> + AsMut<[I]>,
+    I: View,
 {
     fn len(&self) -> usize {
-        self.count()
+        self.items.as_ref().len()
     }
 
     fn at(&self, idx: usize) -> &dyn View {
@@ -186,9 +418,312 @@ where
     }
 }
 
-impl<I, R> MenuItemCollection<R> for Chain<I>
+/// Produces the items of a [`VirtualItems`] collection on demand.
+pub trait ItemSource {
+    type Item: View;
+
+    /// Returns the number of items.
+    fn count(&self) -> usize;
+
+    /// Creates the `nth` item.
+    fn item(&self, nth: usize) -> Self::Item;
+
+    /// Called after the user interacted with the `nth` item, or finished editing it, so that the
+    /// changes can be saved.
+    fn update(&mut self, _nth: usize, _item: Self::Item) {}
+}
+
+/// A collection of items that are created by an [`ItemSource`] when needed.
+///
+/// Only the items that are visible on the display are created while drawing, which makes it
+/// possible to display lists that don't fit into memory. Every item is expected to have the same
+/// height as the first one.
+pub struct VirtualItems<S, F>
+where
+    S: ItemSource,
+{
+    source: S,
+    text_style: F,
+    item_size: Size,
+    /// Used to keep track of the whole collection's position.
+    position: Point,
+    /// The item that is being edited, and its index.
+    editing: Option<(usize, S::Item)>,
+}
+
+impl<S, F> VirtualItems<S, F>
+where
+    S: ItemSource,
+    F: TextRenderer,
+{
+    /// Creates a new collection. The first item is created to measure the item height.
+    pub fn new<R, C>(source: S, text_style: F) -> Self
+    where
+        S::Item: MenuListItem<R, C>,
+        C: PixelColor,
+    {
+        let mut items = Self {
+            source,
+            text_style,
+            item_size: Size::zero(),
+            position: Point::zero(),
+            editing: None,
+        };
+
+        if items.source.count() > 0 {
+            items.item_size = items.create::<R, C>(0).bounds().size;
+        }
+
+        items
+    }
+
+    /// Returns the item source.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the item source.
+    ///
+    /// Changes to the number or height of items are not tracked.
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    fn top_of(&self, nth: usize) -> Point {
+        let top = i32::try_from(nth)
+            .unwrap_or(i32::MAX)
+            .saturating_mul(self.item_size.height as i32);
+
+        Point::new(self.position.x, self.position.y.saturating_add(top))
+    }
+
+    fn create<R, C>(&self, nth: usize) -> S::Item
+    where
+        S::Item: MenuListItem<R, C>,
+        C: PixelColor,
+    {
+        let mut item = self.source.item(nth);
+        MenuListItem::<R, C>::set_style(&mut item, &self.text_style);
+        item.translate_mut(self.top_of(nth));
+        item
+    }
+
+    fn editing(&self, nth: usize) -> Option<&S::Item> {
+        match &self.editing {
+            Some((idx, item)) if *idx == nth => Some(item),
+            _ => None,
+        }
+    }
+
+    /// Runs `f` on the `nth` item, or returns `default` if the source has no such item.
+    fn with_item<R, C, O>(&self, nth: usize, default: O, f: impl FnOnce(&S::Item) -> O) -> O
+    where
+        S::Item: MenuListItem<R, C>,
+        C: PixelColor,
+    {
+        match self.editing(nth) {
+            Some(item) => f(item),
+            None if nth < self.source.count() => f(&self.create::<R, C>(nth)),
+            None => default,
+        }
+    }
+}
+
+impl<S, F, R, C> MenuItemCollection<R, C> for VirtualItems<S, F>
+where
+    S: ItemSource,
+    S::Item: MenuListItem<R, C>,
+    F: TextRenderer,
+    C: PixelColor,
+{
+    fn bounds_of(&self, nth: usize) -> Rectangle {
+        Rectangle::new(self.top_of(nth), self.item_size)
+    }
+
+    fn value_of(&self, nth: usize) -> R {
+        match self.editing(nth) {
+            Some(item) => MenuListItem::<R, C>::value_of(item),
+            None => MenuListItem::<R, C>::value_of(&self.create::<R, C>(nth)),
+        }
+    }
+
+    fn interact_with(&mut self, nth: usize) -> R {
+        let mut item = self.create::<R, C>(nth);
+        let value = MenuListItem::<R, C>::interact(&mut item);
+        self.source.update(nth, item);
+        value
+    }
+
+    fn selectable(&self, nth: usize) -> bool {
+        self.with_item::<R, C, _>(nth, false, MenuListItem::<R, C>::selectable)
+    }
+
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        self.with_item::<R, C, _>(nth, None, MenuListItem::<R, C>::key)
+    }
+
+    fn hint_of(&self, nth: usize) -> Option<&'static str> {
+        self.with_item::<R, C, _>(nth, None, MenuListItem::<R, C>::hint)
+    }
+
+    fn details_of(&self, nth: usize) -> Option<&'static str> {
+        self.with_item::<R, C, _>(nth, None, MenuListItem::<R, C>::details)
+    }
+
+    fn confirmation_of(&self, nth: usize) -> Option<ConfirmDialog> {
+        self.with_item::<R, C, _>(nth, None, MenuListItem::<R, C>::confirmation)
+    }
+
+    fn is_editing(&self, nth: usize) -> bool {
+        self.editing(nth)
+            .is_some_and(|item| MenuListItem::<R, C>::is_editing(item))
+    }
+
+    fn begin_edit(&mut self, nth: usize) -> bool {
+        if nth >= self.source.count() {
+            return false;
+        }
+
+        let mut item = self.create::<R, C>(nth);
+        if !MenuListItem::<R, C>::begin_edit(&mut item) {
+            return false;
+        }
+
+        self.editing = Some((nth, item));
+        true
+    }
+
+    fn edit(&mut self, nth: usize, action: EditAction) -> Option<R> {
+        let (idx, mut item) = self.editing.take()?;
+        debug_assert!(idx == nth);
+
+        let value = MenuListItem::<R, C>::edit(&mut item, action);
+        if MenuListItem::<R, C>::is_editing(&item) {
+            self.editing = Some((idx, item));
+        } else {
+            self.source.update(idx, item);
+        }
+
+        value
+    }
+
+    fn picker_of(&self, nth: usize, option: &mut dyn FnMut(&str, bool)) -> bool {
+        // Pickers are only open while the item is being edited.
+        self.editing(nth)
+            .is_some_and(|item| MenuListItem::<R, C>::picker(item, option))
+    }
+
+    fn marquee_overflow<T: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &T,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.with_item::<R, C, _>(nth, 0, |item| {
+            MenuListItem::<R, C>::marquee_overflow(item, text_style, marquee, width)
+        })
+    }
+
+    fn count(&self) -> usize {
+        self.source.count()
+    }
+
+    fn draw_styled<T, D>(
+        &self,
+        text_style: &T,
+        context: &DrawContext,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        T: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        let count = self.source.count();
+        let height = self.item_size.height as i32;
+        if count == 0 || height == 0 {
+            return Ok(());
+        }
+
+        // Only create the visible items.
+        let display_area = display.bounding_box();
+        let Some(bottom_right) = display_area.bottom_right() else {
+            return Ok(());
+        };
+
+        let first = (display_area.top_left.y - self.position.y).max(0) / height;
+        let last = (bottom_right.y - self.position.y) / height;
+        if last < 0 {
+            return Ok(());
+        }
+
+        for nth in first as usize..count.min(last as usize + 1) {
+            let context = context.nth(nth);
+            match self.editing(nth) {
+                Some(item) => {
+                    MenuListItem::<R, C>::draw_with_context(item, text_style, &context, display)?
+                }
+                None => {
+                    let item = self.create::<R, C>(nth);
+                    MenuListItem::<R, C>::draw_with_context(&item, text_style, &context, display)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_submenu<V>(&self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        self.with_item::<R, C, _>(nth, None, |item| {
+            MenuListItem::<R, C>::visit_submenu(item, path, visitor)
+        })
+    }
+
+    fn visit_submenu_mut<V>(&mut self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        if nth >= self.source.count() {
+            return None;
+        }
+
+        let mut item = self.create::<R, C>(nth);
+        let output = MenuListItem::<R, C>::visit_submenu_mut(&mut item, path, visitor);
+        if output.is_some() {
+            self.source.update(nth, item);
+        }
+        output
+    }
+}
+
+impl<S, F> View for VirtualItems<S, F>
+where
+    S: ItemSource,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        if let Some((_, item)) = &mut self.editing {
+            item.translate_impl(by);
+        }
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let height = u32::try_from(self.source.count())
+            .unwrap_or(u32::MAX)
+            .saturating_mul(self.item_size.height)
+            .min(i32::MAX as u32);
+
+        Rectangle::new(self.position, Size::new(self.item_size.width, height))
+    }
+}
+
+impl<I, R, C> MenuItemCollection<R, C> for Chain<I>
 where
-    I: MenuItemCollection<R>,
+    I: MenuItemCollection<R, C>,
+    C: PixelColor,
 {
     fn bounds_of(&self, nth: usize) -> Rectangle {
         self.object.bounds_of(nth)
@@ -206,26 +741,90 @@ where
         self.object.selectable(nth)
     }
 
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        self.object.key_of(nth)
+    }
+
+    fn hint_of(&self, nth: usize) -> Option<&'static str> {
+        self.object.hint_of(nth)
+    }
+
+    fn details_of(&self, nth: usize) -> Option<&'static str> {
+        self.object.details_of(nth)
+    }
+
+    fn confirmation_of(&self, nth: usize) -> Option<ConfirmDialog> {
+        self.object.confirmation_of(nth)
+    }
+
+    fn linked_items(&self, nth: usize) -> Range<usize> {
+        self.object.linked_items(nth)
+    }
+
+    fn is_editing(&self, nth: usize) -> bool {
+        self.object.is_editing(nth)
+    }
+
+    fn begin_edit(&mut self, nth: usize) -> bool {
+        self.object.begin_edit(nth)
+    }
+
+    fn edit(&mut self, nth: usize, action: EditAction) -> Option<R> {
+        self.object.edit(nth, action)
+    }
+
+    fn picker_of(&self, nth: usize, option: &mut dyn FnMut(&str, bool)) -> bool {
+        self.object.picker_of(nth, option)
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.object
+            .marquee_overflow(nth, text_style, marquee, width)
+    }
+
     fn count(&self) -> usize {
         self.object.count()
     }
 
-    fn draw_styled<D>(
+    fn draw_styled<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.object.draw_styled(text_style, context, display)
+    }
+
+    fn visit_submenu<V>(&self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        self.object.visit_submenu(nth, path, visitor)
+    }
+
+    fn visit_submenu_mut<V>(&mut self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
     {
-        self.object.draw_styled(text_style, display)
+        self.object.visit_submenu_mut(nth, path, visitor)
     }
 }
 
-impl<I, LE, R> MenuItemCollection<R> for Link<I, LE>
+impl<I, LE, R, C> MenuItemCollection<R, C> for Link<I, LE>
 where
-    I: MenuItemCollection<R>,
-    LE: MenuItemCollection<R> + ChainElement,
+    I: MenuItemCollection<R, C>,
+    LE: MenuItemCollection<R, C> + ChainElement,
+    C: PixelColor,
 {
     fn bounds_of(&self, nth: usize) -> Rectangle {
         let count = self.parent.count();
@@ -263,21 +862,251 @@ where
         }
     }
 
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.key_of(nth)
+        } else {
+            self.object.key_of(nth - count)
+        }
+    }
+
+    fn hint_of(&self, nth: usize) -> Option<&'static str> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.hint_of(nth)
+        } else {
+            self.object.hint_of(nth - count)
+        }
+    }
+
+    fn details_of(&self, nth: usize) -> Option<&'static str> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.details_of(nth)
+        } else {
+            self.object.details_of(nth - count)
+        }
+    }
+
+    fn confirmation_of(&self, nth: usize) -> Option<ConfirmDialog> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.confirmation_of(nth)
+        } else {
+            self.object.confirmation_of(nth - count)
+        }
+    }
+
+    fn linked_items(&self, nth: usize) -> Range<usize> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.linked_items(nth)
+        } else {
+            let linked = self.object.linked_items(nth - count);
+            linked.start + count..linked.end + count
+        }
+    }
+
+    fn is_editing(&self, nth: usize) -> bool {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.is_editing(nth)
+        } else {
+            self.object.is_editing(nth - count)
+        }
+    }
+
+    fn begin_edit(&mut self, nth: usize) -> bool {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.begin_edit(nth)
+        } else {
+            self.object.begin_edit(nth - count)
+        }
+    }
+
+    fn edit(&mut self, nth: usize, action: EditAction) -> Option<R> {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.edit(nth, action)
+        } else {
+            self.object.edit(nth - count, action)
+        }
+    }
+
+    fn picker_of(&self, nth: usize, option: &mut dyn FnMut(&str, bool)) -> bool {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.picker_of(nth, option)
+        } else {
+            self.object.picker_of(nth - count, option)
+        }
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent
+                .marquee_overflow(nth, text_style, marquee, width)
+        } else {
+            self.object
+                .marquee_overflow(nth - count, text_style, marquee, width)
+        }
+    }
+
     fn count(&self) -> usize {
         self.object.count() + self.parent.count()
     }
 
-    fn draw_styled<D>(
+    fn draw_styled<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
     {
-        self.parent.draw_styled(text_style, display)?;
-        self.object.draw_styled(text_style, display)?;
+        self.parent.draw_styled(text_style, context, display)?;
+        self.object
+            .draw_styled(text_style, &context.nth(self.parent.count()), display)?;
 
         Ok(())
     }
+
+    fn visit_submenu<V>(&self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.visit_submenu(nth, path, visitor)
+        } else {
+            self.object.visit_submenu(nth - count, path, visitor)
+        }
+    }
+
+    fn visit_submenu_mut<V>(&mut self, nth: usize, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        let count = self.parent.count();
+        if nth < count {
+            self.parent.visit_submenu_mut(nth, path, visitor)
+        } else {
+            self.object.visit_submenu_mut(nth - count, path, visitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        items::NumericItem,
+        test_utils::mock_display,
+        Menu,
+    };
+    use embedded_graphics::{pixelcolor::BinaryColor, Drawable};
+
+    #[test]
+    fn empty_item_source_does_not_panic() {
+        use crate::{collection::ItemSource, MenuStyle};
+
+        struct Empty;
+
+        impl ItemSource for Empty {
+            type Item = NumericItem<&'static str, u32, u32>;
+
+            fn count(&self) -> usize {
+                0
+            }
+
+            fn item(&self, nth: usize) -> Self::Item {
+                unreachable!("item {nth} of an empty source was created")
+            }
+        }
+
+        let mut menu = Menu::with_style("Root", MenuStyle::new(BinaryColor::On).with_footer())
+            .add_virtual_items(Empty)
+            .build();
+
+        let mut display = mock_display();
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.interact(Interaction::Navigation(Navigation::End));
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        assert_eq!(menu.interact(Interaction::Action(Action::Back)), None);
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+    }
+
+    #[test]
+    fn virtual_items_are_created_on_demand() {
+        use core::cell::Cell;
+
+        use crate::collection::ItemSource;
+
+        struct Source {
+            values: Vec<u32>,
+            created: Cell<usize>,
+        }
+
+        impl ItemSource for Source {
+            type Item = NumericItem<&'static str, u32, u32>;
+
+            fn count(&self) -> usize {
+                self.values.len()
+            }
+
+            fn item(&self, nth: usize) -> Self::Item {
+                self.created.set(self.created.get() + 1);
+                NumericItem::new("Value", self.values[nth], 0, 100).with_value_converter(|v| v)
+            }
+
+            fn update(&mut self, nth: usize, item: Self::Item) {
+                self.values[nth] = item.value();
+            }
+        }
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_item("First", (), |_| 1000)
+            .add_virtual_items(Source {
+                values: (0..10_000).map(|v| v % 100).collect(),
+                created: Cell::new(0),
+            })
+            .build();
+
+        menu.interact(Interaction::Navigation(Navigation::End));
+        assert_eq!(menu.selected_value(), 99);
+
+        let mut display = mock_display();
+        menu.update(&display);
+
+        let created_before = menu.items.object.source().created.get();
+        menu.draw(&mut display).unwrap();
+        let created = menu.items.object.source().created.get() - created_before;
+        assert!(
+            created < 10,
+            "{created} items were created to draw the menu"
+        );
+
+        // Edits are stored in the source
+        menu.interact(Interaction::Action(Action::Select));
+        menu.interact(Interaction::Navigation(Navigation::Previous));
+        assert_eq!(menu.selected_value(), 98);
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(98));
+        assert_eq!(menu.items.object.source().values[9999], 98);
+        assert_eq!(menu.selected_value(), 98);
+    }
 }
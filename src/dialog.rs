@@ -0,0 +1,389 @@
+//! Modal dialogs drawn over the menu.
+//!
+//! While a dialog is open, it receives the user input instead of the menu items.
+
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, DrawTargetExt, Point, Primitive, Size},
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_layout::prelude::*;
+use embedded_text::{
+    alignment::HorizontalAlignment,
+    style::{HeightMode, TextBoxStyleBuilder},
+    TextBox,
+};
+
+use crate::{items::text_width, theme::Theme, MenuTextStyle};
+
+/// The index of the button that confirms a [`ConfirmDialog`].
+pub(crate) const CONFIRM: usize = 0;
+
+/// A dialog that asks the user to confirm an action, e.g. a factory reset.
+///
+/// Attach the dialog to an item using [`MenuItem::with_confirmation`] to only return the item's
+/// value after the user confirmed it, or open it using [`Menu::open_dialog`].
+///
+/// [`MenuItem::with_confirmation`]: crate::items::MenuItem::with_confirmation
+/// [`Menu::open_dialog`]: crate::Menu::open_dialog
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfirmDialog {
+    title: &'static str,
+    message: &'static str,
+    confirm: &'static str,
+    cancel: &'static str,
+}
+
+impl ConfirmDialog {
+    /// Creates a dialog with "Yes" and "No" buttons. "No" is selected when the dialog opens.
+    pub const fn new(title: &'static str, message: &'static str) -> Self {
+        Self {
+            title,
+            message,
+            confirm: "Yes",
+            cancel: "No",
+        }
+    }
+
+    /// Sets the labels of the buttons that confirm and cancel the action.
+    pub const fn with_labels(self, confirm: &'static str, cancel: &'static str) -> Self {
+        Self {
+            confirm,
+            cancel,
+            ..self
+        }
+    }
+
+    pub(crate) fn modal(&self, selected: usize) -> ModalBox<'static, 2> {
+        ModalBox {
+            title: self.title,
+            message: self.message,
+            buttons: [self.confirm, self.cancel],
+            selected,
+        }
+    }
+}
+
+/// A message that blocks the menu until the user dismisses it using [`Action::Select`], e.g.
+/// "Calibration failed".
+///
+/// Open the message box using [`Menu::show_message_box`].
+///
+/// [`Action::Select`]: crate::interaction::Action::Select
+/// [`Menu::show_message_box`]: crate::Menu::show_message_box
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageBox {
+    title: &'static str,
+    message: &'static str,
+    button: &'static str,
+}
+
+impl MessageBox {
+    /// Creates a message box with an "OK" button.
+    pub const fn new(title: &'static str, message: &'static str) -> Self {
+        Self {
+            title,
+            message,
+            button: "OK",
+        }
+    }
+
+    /// Sets the label of the button that dismisses the message box.
+    pub const fn with_button(self, button: &'static str) -> Self {
+        Self { button, ..self }
+    }
+
+    pub(crate) fn modal(&self) -> ModalBox<'static, 1> {
+        ModalBox {
+            title: self.title,
+            message: self.message,
+            buttons: [self.button],
+            selected: 0,
+        }
+    }
+}
+
+/// A box with a title, a word-wrapped message and a row of buttons, centered on the display.
+pub(crate) struct ModalBox<'a, const N: usize> {
+    pub title: &'a str,
+    pub message: &'a str,
+    pub buttons: [&'a str; N],
+    pub selected: usize,
+}
+
+impl<const N: usize> ModalBox<'_, N> {
+    /// The distance between the box and the edges of the display.
+    const MARGIN: u32 = 4;
+    /// The border and the gap between the border and the content.
+    const PADDING: u32 = 2;
+
+    fn message_box<'t, C, F>(
+        message: &'t str,
+        text_style: F,
+        top_left: Point,
+        width: u32,
+    ) -> TextBox<'t, F>
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+    {
+        TextBox::with_textbox_style(
+            message,
+            Rectangle::new(top_left, Size::new(width, 0)),
+            text_style,
+            TextBoxStyleBuilder::new()
+                .alignment(HorizontalAlignment::Center)
+                .height_mode(HeightMode::FitToText)
+                .build(),
+        )
+    }
+
+    fn title_height<C, F: MenuTextStyle<C>>(&self, text_style: &F) -> u32 {
+        if self.title.is_empty() {
+            0
+        } else {
+            // The title and a separator line
+            text_style.line_height() + 2
+        }
+    }
+
+    fn button_height<C, F: MenuTextStyle<C>>(text_style: &F) -> u32 {
+        if N == 0 {
+            0
+        } else {
+            // A gap above the buttons, and a one pixel frame around the labels
+            text_style.line_height() + 3
+        }
+    }
+
+    /// Returns the area covered by the box.
+    pub(crate) fn area<C, F>(&self, text_style: F, display_area: Rectangle) -> Rectangle
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+    {
+        let width = display_area
+            .size
+            .width
+            .saturating_sub(2 * Self::MARGIN)
+            .max(2 * Self::PADDING + 1);
+        let content_width = width - 2 * Self::PADDING;
+
+        let message = Self::message_box(self.message, text_style, Point::zero(), content_width);
+        let height = 2 * Self::PADDING
+            + self.title_height(&text_style)
+            + message.bounds.size.height
+            + Self::button_height(&text_style);
+
+        Rectangle::new(
+            Point::zero(),
+            Size::new(width, height.min(display_area.size.height)),
+        )
+        .align_to(&display_area, horizontal::Center, vertical::Center)
+    }
+
+    pub(crate) fn draw<T, F, D>(
+        &self,
+        theme: &T,
+        mut text_style: F,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        T: Theme,
+        F: MenuTextStyle<T::Color>,
+        D: DrawTarget<Color = T::Color>,
+    {
+        let area = self.area(text_style, display.bounding_box());
+        let mut display = display.clipped(&area);
+
+        area.into_styled(PrimitiveStyle::with_fill(theme.background_color()))
+            .draw(&mut display)?;
+        area.into_styled(PrimitiveStyle::with_stroke(theme.text_color(), 1))
+            .draw(&mut display)?;
+
+        text_style.set_background_color(None);
+        text_style.set_text_color(Some(theme.text_color()));
+
+        let content = area.offset(-(Self::PADDING as i32));
+        let mut top = content.top_left.y;
+
+        if !self.title.is_empty() {
+            Text::with_baseline(
+                self.title,
+                Point::new(content.top_left.x, top),
+                text_style,
+                Baseline::Top,
+            )
+            .draw(&mut display)?;
+            top += text_style.line_height() as i32;
+
+            Line::new(
+                Point::new(area.top_left.x, top),
+                Point::new(area.top_left.x + area.size.width as i32 - 1, top),
+            )
+            .into_styled(PrimitiveStyle::with_stroke(theme.text_color(), 1))
+            .draw(&mut display)?;
+            top += 2;
+        }
+
+        let message = Self::message_box(
+            self.message,
+            text_style,
+            Point::new(content.top_left.x, top),
+            content.size.width,
+        );
+        message.draw(&mut display)?;
+
+        if N == 0 {
+            return Ok(());
+        }
+
+        // Buttons are laid out in a centered row, at the bottom of the box.
+        let line_height = text_style.line_height();
+        let gap = text_width(" ", &text_style);
+        let widths = self.buttons.map(|label| text_width(label, &text_style) + 2);
+        let row_width = widths.iter().sum::<u32>() + gap * (N as u32 - 1);
+
+        let mut x = content.center().x - row_width as i32 / 2;
+        let y = content.top_left.y + content.size.height as i32 - line_height as i32 - 2;
+
+        for (idx, (label, width)) in self.buttons.iter().zip(widths).enumerate() {
+            let button = Rectangle::new(Point::new(x, y), Size::new(width, line_height + 2));
+
+            let mut style = text_style;
+            if idx == self.selected {
+                button
+                    .into_styled(PrimitiveStyle::with_fill(theme.selection_color()))
+                    .draw(&mut display)?;
+                style.set_text_color(Some(theme.selected_text_color()));
+            } else {
+                button
+                    .into_styled(PrimitiveStyle::with_stroke(theme.text_color(), 1))
+                    .draw(&mut display)?;
+            }
+
+            Text::with_baseline(
+                label,
+                button.top_left + Point::new(1, 1),
+                style,
+                Baseline::Top,
+            )
+            .draw(&mut display)?;
+
+            x += (width + gap) as i32;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        test_utils::{assert_redrawn, has_pixel, menu_with_items, mock_display},
+        {Menu, MenuStyle},
+    };
+    use embedded_graphics::{pixelcolor::BinaryColor, prelude::Point};
+
+    #[test]
+    fn confirmation_dialog_returns_value_only_when_confirmed() {
+        use crate::{dialog::ConfirmDialog, items::MenuItem};
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_menu_item(
+                MenuItem::new("Factory reset", ())
+                    .with_value_converter(|_| 1)
+                    .with_confirmation(ConfirmDialog::new("Reset", "Erase all settings?")),
+            )
+            .build();
+
+        let mut display = mock_display();
+        menu.draw_partial(&mut display).unwrap();
+
+        // "No" is selected by default.
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        assert!(menu.is_dialog_open());
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let (_, _, dialog_area) = menu.last_drawn.unwrap().dialog.unwrap();
+        assert_redrawn(&regions, dialog_area);
+
+        // The dialog clears its area and draws a border around its content.
+        assert_eq!(
+            display.get_pixel(dialog_area.top_left),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(
+            display.get_pixel(dialog_area.top_left + Point::new(1, 1)),
+            Some(BinaryColor::Off)
+        );
+        assert!(has_pixel(&display, dialog_area.offset(-2), BinaryColor::On));
+
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        assert!(!menu.is_dialog_open());
+
+        menu.interact(Interaction::Action(Action::Select));
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(1));
+        assert!(!menu.is_dialog_open());
+
+        // Dialogs opened by the application return the given value, and can be dismissed.
+        menu.open_dialog(ConfirmDialog::new("", "Restart now?"), 2);
+        assert_eq!(menu.interact(Interaction::Action(Action::Back)), None);
+        assert!(!menu.is_dialog_open());
+
+        menu.open_dialog(ConfirmDialog::new("", "Restart now?"), 2);
+        menu.interact(Interaction::Navigation(Navigation::Previous));
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(2));
+    }
+
+    #[test]
+    fn toasts_expire_and_message_boxes_block_input() {
+        use crate::dialog::MessageBox;
+
+        let mut menu = menu_with_items(MenuStyle::new(BinaryColor::On), 2).build();
+
+        let mut display = mock_display();
+        menu.draw_partial(&mut display).unwrap();
+        let layout = menu.last_drawn.unwrap();
+
+        menu.show_toast("Saved", 2);
+        assert!(menu.is_animating(&display));
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+
+        let snapshot = menu.last_drawn.unwrap();
+        let (_, toast_area) = snapshot.toast.unwrap();
+        assert!(snapshot.same_layout(&layout));
+        assert_eq!(snapshot.selection_area, layout.selection_area);
+
+        // The toast is filled using the selection color, the text uses the selected text color.
+        assert_eq!(
+            display.get_pixel(toast_area.top_left),
+            Some(BinaryColor::On)
+        );
+        assert!(has_pixel(&display, toast_area, BinaryColor::Off));
+
+        menu.update(&display);
+        let regions = menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.toast(), None);
+        assert_redrawn(&regions, toast_area);
+        assert!(!has_pixel(&display, toast_area, BinaryColor::On));
+        assert!(!menu.is_animating(&display));
+
+        menu.show_message_box(MessageBox::new("Error", "Calibration failed"));
+        menu.draw_partial(&mut display).unwrap();
+        assert!(menu.last_drawn.unwrap().message_box.is_some());
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        assert_eq!(menu.selected_value(), 0);
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        assert!(!menu.is_message_box_open());
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        assert_eq!(menu.selected_value(), 1);
+    }
+}
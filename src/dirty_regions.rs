@@ -0,0 +1,208 @@
+use embedded_graphics::{
+    prelude::Point,
+    primitives::{ContainsPoint, Rectangle},
+};
+
+/// Maximum number of separate rectangles tracked by [`DirtyRegions`].
+pub const MAX_REGIONS: usize = 4;
+
+/// The areas of the display that changed since the menu was last drawn.
+///
+/// Overlapping rectangles are merged. If more than [`MAX_REGIONS`] separate areas change, they
+/// are merged into a single rectangle that contains all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DirtyRegions {
+    count: usize,
+    regions: [Rectangle; MAX_REGIONS],
+}
+
+impl Default for DirtyRegions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DirtyRegions {
+    /// Creates an empty set of regions.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            regions: [Rectangle::zero(); MAX_REGIONS],
+        }
+    }
+
+    /// Marks `area` as changed.
+    pub fn add(&mut self, mut area: Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+
+        // Merge with every overlapping region. Merging may make the area overlap regions that were
+        // checked before, so start over until nothing changes.
+        let mut i = 0;
+        while i < self.count {
+            if overlaps(&self.regions[i], &area) {
+                area = envelope(&self.regions[i], &area);
+                self.remove(i);
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+
+        if self.count == MAX_REGIONS {
+            self.regions[0] = self.iter().fold(area, |acc, region| envelope(&acc, region));
+            self.count = 1;
+        } else {
+            self.regions[self.count] = area;
+            self.count += 1;
+        }
+    }
+
+    /// Returns whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the number of separate regions.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the changed rectangles.
+    pub fn iter(&self) -> impl Iterator<Item = &Rectangle> + '_ {
+        self.regions[..self.count].iter()
+    }
+
+    /// Returns the smallest rectangle that contains every changed region.
+    pub fn bounding_box(&self) -> Rectangle {
+        self.iter()
+            .copied()
+            .reduce(|acc, region| envelope(&acc, &region))
+            .unwrap_or(Rectangle::zero())
+    }
+
+    fn remove(&mut self, idx: usize) {
+        self.regions.copy_within(idx + 1..self.count, idx);
+        self.count -= 1;
+    }
+}
+
+impl ContainsPoint for DirtyRegions {
+    fn contains(&self, point: Point) -> bool {
+        self.iter().any(|region| region.contains(point))
+    }
+}
+
+fn overlaps(a: &Rectangle, b: &Rectangle) -> bool {
+    !a.intersection(b).is_zero_sized()
+}
+
+fn envelope(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    let (Some(a_bottom_right), Some(b_bottom_right)) = (a.bottom_right(), b.bottom_right()) else {
+        return if a.is_zero_sized() { *b } else { *a };
+    };
+
+    Rectangle::with_corners(
+        a.top_left.component_min(b.top_left),
+        a_bottom_right.component_max(b_bottom_right),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        test_utils::mock_display,
+        Menu,
+    };
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{Dimensions, DrawTarget, Size},
+        Drawable,
+    };
+
+    use super::*;
+
+    fn rect(x: i32, y: i32, w: u32, h: u32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(w, h))
+    }
+
+    #[test]
+    fn overlapping_regions_are_merged() {
+        let mut regions = DirtyRegions::new();
+
+        regions.add(rect(0, 0, 10, 10));
+        regions.add(rect(0, 20, 10, 10));
+        assert_eq!(regions.len(), 2);
+
+        regions.add(rect(5, 5, 10, 20));
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.bounding_box(), rect(0, 0, 15, 30));
+    }
+
+    #[test]
+    fn too_many_regions_are_merged() {
+        let mut regions = DirtyRegions::new();
+
+        for i in 0..=MAX_REGIONS as i32 {
+            regions.add(rect(0, i * 10, 5, 5));
+        }
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.bounding_box(), rect(0, 0, 5, 45));
+    }
+
+    #[test]
+    fn empty_regions_are_ignored() {
+        let mut regions = DirtyRegions::new();
+        regions.add(rect(3, 3, 0, 10));
+
+        assert!(regions.is_empty());
+        assert_eq!(regions.bounding_box(), Rectangle::zero());
+    }
+
+    #[test]
+    fn partial_draw_matches_full_draw() {
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_item("Item 0", false, |_| 0)
+            .add_item("Item 1", false, |_| 1)
+            .add_item("Item 2", false, |_| 2)
+            .build();
+
+        let mut partial = mock_display();
+        let display_area = partial.bounding_box();
+
+        let mut draw_partial = |menu: &mut Menu<_, _, _, _, _, _, BinaryColor, _, _, _>| {
+            menu.update(&partial);
+            let regions = menu.draw_partial(&mut partial).unwrap();
+
+            let mut full = mock_display();
+            full.clear(BinaryColor::Off).unwrap();
+            menu.draw(&mut full).unwrap();
+
+            // The partially updated frame must look like a freshly drawn one.
+            assert_eq!(partial, full);
+
+            regions
+        };
+
+        let first = draw_partial(&mut menu);
+        assert_eq!(first.bounding_box(), display_area);
+
+        assert!(draw_partial(&mut menu).is_empty());
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        let moved = draw_partial(&mut menu);
+        assert!(!moved.is_empty());
+        assert!(moved.bounding_box().size.height < display_area.size.height);
+
+        menu.interact(Interaction::Action(Action::Select));
+        let changed = draw_partial(&mut menu);
+        assert!(!changed.is_empty());
+        assert!(changed.bounding_box().size.height < display_area.size.height);
+
+        menu.invalidate();
+        assert_eq!(draw_partial(&mut menu).bounding_box(), display_area);
+    }
+}
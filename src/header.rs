@@ -0,0 +1,260 @@
+//! Menu header styles.
+//!
+//! The header displays the title of the current (sub)menu and a status text, set using
+//! [`Menu::set_status`](crate::Menu::set_status). Fonts that contain icon glyphs can be used to
+//! display icons, like a battery level, in the status text.
+
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point, Primitive, Size},
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_text::{
+    style::{HeightMode, TextBoxStyleBuilder},
+    TextBox,
+};
+
+use crate::{items::text_width, theme::Theme, MenuTextStyle};
+
+pub use embedded_text::alignment::HorizontalAlignment;
+
+/// The separator between the header and the menu list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Separator {
+    None,
+    /// A horizontal line that is this many pixels thick.
+    Line(u32),
+}
+
+/// Controls how the header is displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeaderStyle {
+    alignment: HorizontalAlignment,
+    separator: Separator,
+    inverted: bool,
+}
+
+impl Default for HeaderStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HeaderStyle {
+    /// A left-aligned title, separated from the list by a one pixel thick line.
+    pub const fn new() -> Self {
+        Self {
+            alignment: HorizontalAlignment::Left,
+            separator: Separator::Line(1),
+            inverted: false,
+        }
+    }
+
+    pub const fn with_alignment(self, alignment: HorizontalAlignment) -> Self {
+        Self { alignment, ..self }
+    }
+
+    pub const fn with_separator(self, separator: Separator) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Draws the header text using the theme's selected text color over a filled bar.
+    pub const fn inverted(self) -> Self {
+        Self {
+            inverted: true,
+            ..self
+        }
+    }
+
+    fn separator_height(&self) -> u32 {
+        match self.separator {
+            Separator::None => 0,
+            Separator::Line(thickness) => thickness,
+        }
+    }
+
+    fn status_width<F, C>(status: &str, text_style: &F) -> u32
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+    {
+        if status.is_empty() {
+            0
+        } else {
+            // Leave a gap between the title and the status
+            text_width(status, text_style) + 1
+        }
+    }
+
+    fn title_box<'t, F, C>(
+        &self,
+        title: &'t str,
+        status: &str,
+        text_style: F,
+        area: Rectangle,
+    ) -> TextBox<'t, F>
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+    {
+        let status_width = Self::status_width(status, &text_style);
+        let title_area = Rectangle::new(
+            area.top_left,
+            Size::new(
+                area.size.width.saturating_sub(status_width),
+                area.size.height,
+            ),
+        );
+
+        let mut title = TextBox::with_textbox_style(
+            title,
+            title_area,
+            text_style,
+            TextBoxStyleBuilder::new()
+                .alignment(self.alignment)
+                .height_mode(HeightMode::FitToText)
+                .build(),
+        );
+
+        // Keep the height even if the title is empty
+        title.bounds.size.height = title.bounds.size.height.max(text_style.line_height());
+
+        title
+    }
+
+    /// Returns the area covered by the header, or `None` if there is nothing to display.
+    pub(crate) fn area<F, C>(
+        &self,
+        title: &str,
+        status: &str,
+        text_style: F,
+        display_area: Rectangle,
+    ) -> Option<Rectangle>
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+    {
+        if title.is_empty() && status.is_empty() {
+            return None;
+        }
+
+        let title = self.title_box(title, status, text_style, display_area);
+
+        Some(Rectangle::new(
+            display_area.top_left,
+            Size::new(
+                display_area.size.width,
+                title.bounds.size.height + self.separator_height(),
+            ),
+        ))
+    }
+
+    pub(crate) fn draw<T, F, D>(
+        &self,
+        title: &str,
+        status: &str,
+        theme: &T,
+        mut text_style: F,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        T: Theme,
+        F: MenuTextStyle<T::Color>,
+        D: DrawTarget<Color = T::Color>,
+    {
+        let display_area = display.bounding_box();
+        let Some(area) = self.area(title, status, text_style, display_area) else {
+            return Ok(());
+        };
+
+        let bar_height = area.size.height - self.separator_height();
+        let bar = Rectangle::new(area.top_left, Size::new(area.size.width, bar_height));
+
+        if self.inverted {
+            bar.into_styled(PrimitiveStyle::with_fill(theme.text_color()))
+                .draw(display)?;
+            text_style.set_text_color(Some(theme.selected_text_color()));
+        } else {
+            text_style.set_text_color(Some(theme.text_color()));
+        }
+        text_style.set_background_color(None);
+
+        self.title_box(title, status, text_style, display_area)
+            .draw(display)?;
+
+        if !status.is_empty() {
+            let status_width = text_width(status, &text_style) as i32;
+            Text::with_baseline(
+                status,
+                Point::new(
+                    bar.top_left.x + bar.size.width as i32 - status_width,
+                    bar.top_left.y,
+                ),
+                text_style,
+                Baseline::Top,
+            )
+            .draw(display)?;
+        }
+
+        if let Separator::Line(thickness) = self.separator {
+            Rectangle::new(
+                bar.top_left + Point::new(0, bar_height as i32),
+                Size::new(area.size.width, thickness),
+            )
+            .into_styled(PrimitiveStyle::with_fill(theme.text_color()))
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{menu_with_items, mock_display};
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    #[test]
+    fn status_change_redraws_header() {
+        use crate::{
+            header::{HeaderStyle, HorizontalAlignment, Separator},
+            MenuStyle,
+        };
+        use embedded_graphics::{
+            prelude::{Point, Size},
+            primitives::Rectangle,
+        };
+
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_header(
+                HeaderStyle::new()
+                    .with_alignment(HorizontalAlignment::Center)
+                    .with_separator(Separator::None)
+                    .inverted(),
+            ),
+            2,
+        )
+        .build();
+
+        let mut display = mock_display();
+        menu.draw_partial(&mut display).unwrap();
+
+        // The inverted title bar fills the corners
+        assert_eq!(display.get_pixel(Point::new(63, 0)), Some(BinaryColor::On));
+
+        menu.set_status("12:00");
+        assert_eq!(menu.status(), "12:00");
+
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let header = Rectangle::new(Point::zero(), Size::new(64, 10));
+        assert_eq!(regions.iter().copied().collect::<Vec<_>>(), [header]);
+
+        // The status is drawn in the top right corner, using the inverted text color
+        assert_eq!(display.get_pixel(Point::new(63, 0)), Some(BinaryColor::On));
+        assert!((34..64).any(|x| display.get_pixel(Point::new(x, 2)) == Some(BinaryColor::Off)));
+
+        assert!(menu.draw_partial(&mut display).unwrap().is_empty());
+    }
+}
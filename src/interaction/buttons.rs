@@ -0,0 +1,311 @@
+use crate::{
+    interaction::{
+        Action, InputAdapter, InputAdapterSource, InputResult, InputState, Interaction, Navigation,
+    },
+    selection_indicator::style::interpolate,
+};
+
+/// Moves the selection to the previous item.
+pub const UP: u8 = 1 << 0;
+/// Moves the selection to the next item.
+pub const DOWN: u8 = 1 << 1;
+/// Activates the selected item.
+pub const SELECT: u8 = 1 << 2;
+/// Returns to the parent menu. Holding the button returns [`Buttons::esc_value`].
+pub const BACK: u8 = 1 << 3;
+/// Changes the value of the item being edited, like [`UP`]. Moves the selection to the previous
+/// item when nothing is being edited.
+pub const LEFT: u8 = 1 << 4;
+/// Changes the value of the item being edited, like [`DOWN`]. Moves the selection to the next item
+/// when nothing is being edited.
+pub const RIGHT: u8 = 1 << 5;
+
+const NAVIGATION: u8 = UP | DOWN | LEFT | RIGHT;
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct State {
+    raw: u8,
+    stable_time: u32,
+    pressed: u8,
+    active: u8,
+    held_time: u32,
+    next_repeat: u32,
+    escaped: bool,
+}
+
+/// Discrete buttons, sampled once every update period.
+///
+/// The input is a bit mask of the pressed buttons, made of [`UP`], [`DOWN`], [`SELECT`],
+/// [`BACK`], [`LEFT`] and [`RIGHT`]. Boards without left and right buttons can leave those bits
+/// cleared.
+///
+/// Up, down: move the selection, repeating while held
+/// Left, right: same as up and down, useful to change the value of the item being edited
+/// Select: activate current item
+/// Back: go back, or return `esc_value` when held
+///
+/// The adapter doesn't know whether an item is being edited, so left and right also move the
+/// selection outside of edit mode.
+///
+/// If multiple buttons are pressed, only the last pressed one is handled. Of the buttons pressed
+/// in the same update period, the one with the lowest bit wins, e.g. [`UP`] over [`DOWN`].
+#[derive(Clone, Copy)]
+pub struct Buttons<R>
+where
+    R: Copy,
+{
+    /// Ignores changes of the buttons that last less than this many update periods.
+    pub debounce_time: u32,
+
+    /// Holding a navigation button repeats it after this many update periods.
+    pub repeat_delay: u32,
+
+    /// Once repeating, a held navigation button repeats every this many update periods.
+    pub repeat_period: u32,
+
+    /// Holding the back button for this many update periods returns `esc_value`.
+    pub esc_time: u32,
+
+    pub esc_value: R,
+}
+
+impl<R> InputAdapterSource<R> for Buttons<R>
+where
+    R: Copy,
+{
+    type InputAdapter = Self;
+
+    fn adapter(&self) -> Self::InputAdapter {
+        *self
+    }
+}
+
+impl<R> Buttons<R>
+where
+    R: Copy,
+{
+    /// Returns the buttons that have been stable for at least `debounce_time` update periods.
+    fn debounce(&self, state: &mut State, input: u8) -> u8 {
+        if input == state.raw {
+            state.stable_time = state.stable_time.saturating_add(1);
+        } else {
+            state.raw = input;
+            state.stable_time = 0;
+        }
+
+        if state.stable_time >= self.debounce_time {
+            state.raw
+        } else {
+            state.pressed
+        }
+    }
+
+    fn press(&self, state: &mut State, button: u8) -> InputResult<R> {
+        state.active = button;
+        state.held_time = 0;
+        state.next_repeat = self.repeat_delay;
+        state.escaped = false;
+
+        match button {
+            SELECT => InputResult::from(Interaction::Action(Action::Select)),
+            BACK => InputResult::from(InputState::Idle),
+            _ => InputResult::from(Interaction::Navigation(navigation(button))),
+        }
+    }
+
+    fn hold(&self, state: &mut State) -> InputResult<R> {
+        state.held_time = state.held_time.saturating_add(1);
+
+        match state.active {
+            BACK if !state.escaped => {
+                if state.held_time < self.esc_time {
+                    InputResult::from(InputState::InProgress(interpolate(
+                        state.held_time,
+                        0,
+                        self.esc_time,
+                        0,
+                        255,
+                    ) as u8))
+                } else {
+                    state.escaped = true;
+                    InputResult::from(Interaction::Action(Action::Return(self.esc_value)))
+                }
+            }
+            button if button & NAVIGATION != 0 => {
+                if state.held_time < self.repeat_delay {
+                    InputResult::from(InputState::InProgress(interpolate(
+                        state.held_time,
+                        0,
+                        self.repeat_delay,
+                        0,
+                        255,
+                    ) as u8))
+                } else if state.held_time == state.next_repeat {
+                    state.next_repeat = state.held_time.saturating_add(self.repeat_period.max(1));
+                    InputResult::from(Interaction::Navigation(navigation(button)))
+                } else {
+                    InputResult::from(InputState::Idle)
+                }
+            }
+            _ => InputResult::from(InputState::Idle),
+        }
+    }
+
+    fn release(&self, state: &mut State) -> InputResult<R> {
+        let button = core::mem::replace(&mut state.active, 0);
+
+        if button == BACK && !state.escaped {
+            InputResult::from(Interaction::Action(Action::Back))
+        } else {
+            InputResult::from(InputState::Idle)
+        }
+    }
+}
+
+fn navigation(button: u8) -> Navigation {
+    match button {
+        UP | LEFT => Navigation::Previous,
+        _ => Navigation::Next,
+    }
+}
+
+impl<R> InputAdapter for Buttons<R>
+where
+    R: Copy,
+{
+    type Input = u8;
+    type Value = R;
+    type State = State;
+
+    fn handle_input(
+        &self,
+        state: &mut Self::State,
+        action: Self::Input,
+    ) -> InputResult<Self::Value> {
+        let pressed = self.debounce(state, action);
+        let new = pressed & !state.pressed;
+        state.pressed = pressed;
+
+        if new != 0 {
+            // Handle a single button, the lowest bit wins.
+            self.press(state, new & new.wrapping_neg())
+        } else if state.active & pressed != 0 {
+            self.hold(state)
+        } else if state.active != 0 {
+            self.release(state)
+        } else {
+            InputResult::from(InputState::Idle)
+        }
+    }
+
+    fn hint(&self, editing: bool) -> &'static str {
+        if editing {
+            "back: cancel"
+        } else {
+            "hold back: exit"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interaction::{
+        buttons::{Buttons, BACK, DOWN, SELECT, UP},
+        Action, InputAdapter, InputResult, InputState, Interaction, Navigation,
+    };
+
+    const BUTTONS: Buttons<u8> = Buttons {
+        debounce_time: 1,
+        repeat_delay: 3,
+        repeat_period: 2,
+        esc_time: 4,
+        esc_value: 42,
+    };
+
+    fn run(inputs: &[(u8, InputResult<u8>)]) {
+        let mut state = Default::default();
+
+        for (sample, (input, expectation)) in inputs.iter().enumerate() {
+            assert_eq!(
+                BUTTONS.handle_input(&mut state, *input),
+                *expectation,
+                "Mismatch at sample {sample}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounces_are_ignored() {
+        run(&[
+            (DOWN, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+            (DOWN, InputState::Idle.into()),
+            (DOWN, Interaction::Navigation(Navigation::Next).into()),
+            (0, InputState::InProgress(85).into()),
+            (0, InputState::Idle.into()),
+        ]);
+    }
+
+    #[test]
+    fn held_navigation_repeats() {
+        run(&[
+            (UP, InputState::Idle.into()),
+            (UP, Interaction::Navigation(Navigation::Previous).into()),
+            (UP, InputState::InProgress(85).into()),
+            (UP, InputState::InProgress(170).into()),
+            (UP, Interaction::Navigation(Navigation::Previous).into()),
+            (UP, InputState::Idle.into()),
+            (UP, Interaction::Navigation(Navigation::Previous).into()),
+            (0, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+        ]);
+    }
+
+    #[test]
+    fn select_and_back() {
+        run(&[
+            (SELECT, InputState::Idle.into()),
+            (SELECT, Interaction::Action(Action::Select).into()),
+            (SELECT, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+            // short press goes back on release
+            (BACK, InputState::Idle.into()),
+            (BACK, InputState::Idle.into()),
+            (0, InputState::InProgress(63).into()),
+            (0, Interaction::Action(Action::Back).into()),
+        ]);
+    }
+
+    #[test]
+    fn simultaneous_presses_handle_lowest_bit() {
+        run(&[
+            (DOWN | UP, InputState::Idle.into()),
+            (
+                DOWN | UP,
+                Interaction::Navigation(Navigation::Previous).into(),
+            ),
+            // a later press takes over
+            (DOWN | UP | SELECT, InputState::InProgress(85).into()),
+            (
+                DOWN | UP | SELECT,
+                Interaction::Action(Action::Select).into(),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn held_back_returns_esc_value() {
+        run(&[
+            (BACK, InputState::Idle.into()),
+            (BACK, InputState::Idle.into()),
+            (BACK, InputState::InProgress(63).into()),
+            (BACK, InputState::InProgress(127).into()),
+            (BACK, InputState::InProgress(191).into()),
+            (BACK, Interaction::Action(Action::Return(42)).into()),
+            (BACK, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+            (0, InputState::Idle.into()),
+        ]);
+    }
+}
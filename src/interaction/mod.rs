@@ -1,4 +1,6 @@
+pub mod buttons;
 pub mod programmed;
+pub mod rotary_encoder;
 pub mod single_touch;
 
 #[cfg(feature = "simulator")]
@@ -18,6 +20,8 @@ pub enum Action<R> {
     Select,
     /// Return a value
     Return(R),
+    /// Leave the current submenu and return to its parent.
+    Back,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -52,6 +56,10 @@ impl Navigation {
         count: usize,
         selectable: impl Fn(usize) -> bool,
     ) -> usize {
+        if count == 0 {
+            return 0;
+        }
+
         // Clamp the selection to the range of selectable items.
         selected = selected.clamp(0, count - 1);
         let original = selected;
@@ -98,7 +106,7 @@ impl Navigation {
             Self::BackwardWrapping(n) => {
                 selected = selected
                     .checked_sub(n)
-                    .unwrap_or_else(|| count - (n - selected) % count);
+                    .unwrap_or_else(|| (count - (n - selected) % count) % count);
                 if !selectable(selected) {
                     Self::Previous.calculate_selection(selected, count, selectable)
                 } else {
@@ -179,6 +187,14 @@ pub trait InputAdapter: Copy {
         state: &mut Self::State,
         action: Self::Input,
     ) -> InputResult<Self::Value>;
+
+    /// Returns a short description of the controls, displayed in the footer unless the selected
+    /// item provides a hint. `editing` is true while the selected item is being edited.
+    ///
+    /// See [`MenuStyle::with_footer`](crate::MenuStyle::with_footer).
+    fn hint(&self, _editing: bool) -> &'static str {
+        ""
+    }
 }
 
 #[cfg(test)]
@@ -263,6 +279,29 @@ mod test {
         assert_eq!(selected, 0);
     }
 
+    #[test]
+    fn backward_wrapping_by_multiple_of_count() {
+        let selected = Navigation::BackwardWrapping(12).calculate_selection(2, 10, |_| true);
+        assert_eq!(selected, 0);
+    }
+
+    #[test]
+    fn selection_in_empty_list() {
+        for navigation in [
+            Navigation::Next,
+            Navigation::Previous,
+            Navigation::ForwardWrapping(3),
+            Navigation::Forward(3),
+            Navigation::BackwardWrapping(3),
+            Navigation::Backward(3),
+            Navigation::Beginning,
+            Navigation::End,
+            Navigation::JumpTo(3),
+        ] {
+            assert_eq!(navigation.calculate_selection(2, 0, |_| true), 0);
+        }
+    }
+
     #[test]
     fn unselectable_selection_infinite_loop() {
         let selected = Navigation::BackwardWrapping(75).calculate_selection(5, 10, |_| false);
@@ -0,0 +1,366 @@
+use core::marker::PhantomData;
+
+use crate::{
+    interaction::{
+        Action, InputAdapter, InputAdapterSource, InputResult, InputState, Interaction, Navigation,
+    },
+    selection_indicator::style::interpolate,
+};
+
+/// A single sample of the rotary encoder, taken once every update period.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotaryEncoderInput {
+    /// Number of detents the knob was turned by since the previous sample. Positive values move
+    /// the selection forward.
+    pub delta: i32,
+
+    /// Whether the push switch is pressed.
+    pub pressed: bool,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct State {
+    press_time: u32,
+    was_released: bool,
+    long_pressed: bool,
+    since_last_detent: u32,
+    acceleration: usize,
+    pending_delta: i32,
+}
+
+/// Rotary encoder with a push switch
+///
+/// Turning the knob: move the selection, wrapping around at the ends of the list
+/// Short press: activate current item
+/// Long press: perform `long_press`, e.g. go back
+#[derive(Clone, Copy)]
+pub struct RotaryEncoder<R>
+where
+    R: Copy,
+{
+    /// Does not display short presses on the selection indicator.
+    pub ignore_time: u32,
+
+    /// Ignores presses shorter than this many update periods.
+    pub debounce_time: u32,
+
+    /// Detects long presses after this many update periods.
+    pub max_time: u32,
+
+    /// Turning the knob again within this many update periods speeds up navigation.
+    pub acceleration_time: u32,
+
+    /// The maximum number of items a single detent can move the selection by.
+    pub max_acceleration: usize,
+
+    /// The action performed by a long press, usually [`Action::Back`].
+    pub long_press: Action<R>,
+}
+
+impl<R> InputAdapterSource<R> for RotaryEncoder<R>
+where
+    R: Copy,
+{
+    type InputAdapter = RotaryEncoderAdapter<R>;
+
+    fn adapter(&self) -> Self::InputAdapter {
+        RotaryEncoderAdapter {
+            config: *self,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Rotary encoder with a push switch
+///
+/// Turning the knob: move the selection, wrapping around at the ends of the list
+/// Short press: activate current item
+/// Long press: perform the configured action, e.g. go back
+pub struct RotaryEncoderAdapter<R>
+where
+    R: Copy,
+{
+    config: RotaryEncoder<R>,
+    marker: PhantomData<R>,
+}
+
+impl<R> Clone for RotaryEncoderAdapter<R>
+where
+    R: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for RotaryEncoderAdapter<R> where R: Copy {}
+
+impl<R> RotaryEncoderAdapter<R>
+where
+    R: Copy,
+{
+    fn handle_button(&self, state: &mut State, pressed: bool) -> InputResult<R> {
+        let config = &self.config;
+
+        if !state.was_released {
+            if pressed {
+                return InputResult::from(InputState::Idle);
+            }
+            state.was_released = true;
+        }
+
+        if pressed {
+            state.press_time = state.press_time.saturating_add(1);
+            if state.long_pressed || state.press_time <= config.ignore_time {
+                InputResult::from(InputState::Idle)
+            } else if state.press_time < config.max_time {
+                InputResult::from(InputState::InProgress(interpolate(
+                    state.press_time - config.ignore_time,
+                    0,
+                    config.max_time - config.ignore_time,
+                    0,
+                    255,
+                ) as u8))
+            } else {
+                state.long_pressed = true;
+                InputResult::from(Interaction::Action(config.long_press))
+            }
+        } else {
+            let time = core::mem::replace(&mut state.press_time, 0);
+
+            if config.debounce_time < time && time < config.max_time && !state.long_pressed {
+                InputResult::from(Interaction::Action(Action::Select))
+            } else {
+                // Already interacted before releasing, ignore and reset.
+                state.long_pressed = false;
+                InputResult::from(InputState::Idle)
+            }
+        }
+    }
+
+    fn handle_rotation(&self, state: &mut State, delta: i32) -> Option<Navigation> {
+        let config = &self.config;
+
+        let since_last_detent = core::mem::replace(&mut state.since_last_detent, 0);
+        if delta == 0 {
+            state.since_last_detent = since_last_detent.saturating_add(1);
+            return None;
+        }
+
+        state.acceleration = if since_last_detent < config.acceleration_time {
+            (state.acceleration + 1).min(config.max_acceleration.max(1))
+        } else {
+            1
+        };
+
+        // Always wrap, so that the list behaves the same regardless of the turning speed.
+        let steps = (delta.unsigned_abs() as usize).saturating_mul(state.acceleration);
+        let navigation = match (delta > 0, steps) {
+            (true, 1) => Navigation::Next,
+            (false, 1) => Navigation::Previous,
+            (true, n) => Navigation::ForwardWrapping(n),
+            (false, n) => Navigation::BackwardWrapping(n),
+        };
+
+        Some(navigation)
+    }
+}
+
+impl<R> InputAdapter for RotaryEncoderAdapter<R>
+where
+    R: Copy,
+{
+    type Input = RotaryEncoderInput;
+    type Value = R;
+    type State = State;
+
+    fn handle_input(
+        &self,
+        state: &mut Self::State,
+        action: Self::Input,
+    ) -> InputResult<Self::Value> {
+        let delta = action.delta.saturating_add(state.pending_delta);
+        state.pending_delta = 0;
+
+        let button = self.handle_button(state, action.pressed);
+        if let InputResult::Interaction(_) = button {
+            // Don't lose the rotation, report it with the next sample instead.
+            state.pending_delta = delta;
+            return button;
+        }
+
+        match self.handle_rotation(state, delta) {
+            Some(navigation) => InputResult::from(Interaction::Navigation(navigation)),
+            None => button,
+        }
+    }
+
+    fn hint(&self, editing: bool) -> &'static str {
+        match (editing, self.config.long_press) {
+            (true, _) => "turn to adjust",
+            (false, Action::Back) => "press: ok, hold: back",
+            (false, Action::Select) => "press: ok",
+            (false, Action::Return(_)) => "press: ok, hold: exit",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interaction::{
+        rotary_encoder::{RotaryEncoder, RotaryEncoderInput},
+        Action, InputAdapter, InputAdapterSource, InputResult, InputState, Interaction, Navigation,
+    };
+
+    fn input(delta: i32, pressed: bool) -> RotaryEncoderInput {
+        RotaryEncoderInput { delta, pressed }
+    }
+
+    #[test]
+    fn test_interaction() {
+        // ignore 1 long pulses, accept 2-4 as short press, 5- as long press
+        let controller = RotaryEncoder {
+            ignore_time: 1,
+            debounce_time: 1,
+            max_time: 5,
+            acceleration_time: 2,
+            max_acceleration: 3,
+            long_press: Action::Back,
+        }
+        .adapter();
+
+        let expectations: [&[(RotaryEncoderInput, InputResult<()>)]; 5] = [
+            // short pulse ignored
+            &[
+                (input(0, true), InputState::Idle.into()),
+                (input(0, false), InputState::Idle.into()),
+            ],
+            // longer pulse recognised as Select event on falling edge
+            &[
+                (input(0, false), InputState::Idle.into()),
+                (input(0, true), InputState::Idle.into()),
+                (input(0, true), InputState::InProgress(63).into()),
+                (input(0, false), Interaction::Action(Action::Select).into()),
+            ],
+            // long pulse recognised as Back event immediately, once
+            &[
+                (input(0, false), InputState::Idle.into()),
+                (input(0, true), InputState::Idle.into()),
+                (input(0, true), InputState::InProgress(63).into()),
+                (input(0, true), InputState::InProgress(127).into()),
+                (input(0, true), InputState::InProgress(191).into()),
+                (input(0, true), Interaction::Action(Action::Back).into()),
+                (input(0, true), InputState::Idle.into()),
+                (input(0, false), InputState::Idle.into()),
+            ],
+            // slow turning moves by one item
+            &[
+                (
+                    input(1, false),
+                    Interaction::Navigation(Navigation::Next).into(),
+                ),
+                (input(0, false), InputState::Idle.into()),
+                (input(0, false), InputState::Idle.into()),
+                (
+                    input(-1, false),
+                    Interaction::Navigation(Navigation::Previous).into(),
+                ),
+                (input(0, false), InputState::Idle.into()),
+                (input(0, false), InputState::Idle.into()),
+                (
+                    input(2, false),
+                    Interaction::Navigation(Navigation::ForwardWrapping(2)).into(),
+                ),
+            ],
+            // fast turning accelerates
+            &[
+                (
+                    input(1, false),
+                    Interaction::Navigation(Navigation::Next).into(),
+                ),
+                (
+                    input(1, false),
+                    Interaction::Navigation(Navigation::ForwardWrapping(2)).into(),
+                ),
+                (input(0, false), InputState::Idle.into()),
+                (
+                    input(1, false),
+                    Interaction::Navigation(Navigation::ForwardWrapping(3)).into(),
+                ),
+                (
+                    input(2, false),
+                    Interaction::Navigation(Navigation::ForwardWrapping(6)).into(),
+                ),
+                (input(0, false), InputState::Idle.into()),
+                (input(0, false), InputState::Idle.into()),
+                (
+                    input(-1, false),
+                    Interaction::Navigation(Navigation::Previous).into(),
+                ),
+            ],
+        ];
+
+        for (row, &inputs) in expectations.iter().enumerate() {
+            let mut controller_state = Default::default();
+
+            for (sample, (input, expectation)) in inputs.iter().enumerate() {
+                let ret = controller.handle_input(&mut controller_state, *input);
+
+                assert_eq!(
+                    ret, *expectation,
+                    "Mismatch at row {}, sample {}",
+                    row, sample
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn long_press_action_is_configurable() {
+        let controller = RotaryEncoder {
+            ignore_time: 0,
+            debounce_time: 0,
+            max_time: 2,
+            acceleration_time: 0,
+            max_acceleration: 1,
+            long_press: Action::Return(7),
+        }
+        .adapter();
+
+        let mut state = Default::default();
+
+        let _ = controller.handle_input(&mut state, input(0, false));
+        let _ = controller.handle_input(&mut state, input(0, true));
+        assert_eq!(
+            controller.handle_input(&mut state, input(0, true)),
+            Interaction::Action(Action::Return(7)).into()
+        );
+        assert_eq!(controller.hint(false), "press: ok, hold: exit");
+    }
+
+    #[test]
+    fn rotation_during_button_event_is_not_lost() {
+        let controller = RotaryEncoder {
+            ignore_time: 0,
+            debounce_time: 0,
+            max_time: 5,
+            acceleration_time: 0,
+            max_acceleration: 1,
+            long_press: Action::Back,
+        }
+        .adapter();
+
+        let mut state = Default::default();
+
+        let _: InputResult<()> = controller.handle_input(&mut state, input(0, false));
+        let _ = controller.handle_input(&mut state, input(0, true));
+        assert_eq!(
+            controller.handle_input(&mut state, input(1, false)),
+            Interaction::Action(Action::Select).into()
+        );
+        assert_eq!(
+            controller.handle_input(&mut state, input(0, false)),
+            Interaction::Navigation(Navigation::Next).into()
+        );
+    }
+}
@@ -54,6 +54,9 @@ where
                 Keycode::PageUp => InputResult::from(Interaction::Navigation(
                     Navigation::Backward(self.page_size),
                 )),
+                Keycode::Backspace | Keycode::Left => {
+                    InputResult::from(Interaction::Action(Action::Back))
+                }
                 Keycode::Escape => {
                     InputResult::from(Interaction::Action(Action::Return(self.esc_value)))
                 }
@@ -65,4 +68,12 @@ where
             _ => InputResult::from(InputState::Idle),
         }
     }
+
+    fn hint(&self, editing: bool) -> &'static str {
+        if editing {
+            "up/down: adjust"
+        } else {
+            "enter: ok, left: back"
+        }
+    }
 }
@@ -18,6 +18,7 @@ pub struct State {
 ///
 /// Short press: select next item
 /// Long press: activate current item
+/// Very long press: return to the parent menu, if `back_time` is set
 #[derive(Clone, Copy)]
 pub struct SingleTouch {
     /// Does not display short presses on the selection indicator.
@@ -28,6 +29,13 @@ pub struct SingleTouch {
 
     /// Detects long presses after this many update periods.
     pub max_time: u32,
+
+    /// Detects very long presses after this many update periods, which send [`Action::Back`].
+    ///
+    /// If this is larger than `max_time`, long presses are activated when the touch is released,
+    /// otherwise they are activated immediately and repeated while the touch is held. Set to 0 to
+    /// disable very long presses.
+    pub back_time: u32,
 }
 
 impl<R> InputAdapterSource<R> for SingleTouch {
@@ -38,6 +46,7 @@ impl<R> InputAdapterSource<R> for SingleTouch {
             ignore_time: self.ignore_time,
             debounce_time: self.debounce_time,
             max_time: self.max_time,
+            back_time: self.back_time,
             marker: PhantomData,
         }
     }
@@ -47,10 +56,12 @@ impl<R> InputAdapterSource<R> for SingleTouch {
 ///
 /// Short press: select next item
 /// Long press: activate current item
+/// Very long press: return to the parent menu, if `back_time` is set
 pub struct SingleTouchAdapter<R> {
     ignore_time: u32,
     debounce_time: u32,
     max_time: u32,
+    back_time: u32,
     marker: PhantomData<R>,
 }
 
@@ -80,6 +91,11 @@ impl<R> InputAdapter for SingleTouchAdapter<R> {
         }
 
         if action {
+            if state.repeated && self.back_time > self.max_time {
+                // The very long press was already handled, wait for the release.
+                return InputResult::from(InputState::Idle);
+            }
+
             state.interaction_time = state.interaction_time.saturating_add(1);
             if state.interaction_time <= self.ignore_time && !state.repeated {
                 InputResult::from(InputState::Idle)
@@ -92,6 +108,15 @@ impl<R> InputAdapter for SingleTouchAdapter<R> {
                     0,
                     255,
                 ) as u8))
+            } else if self.back_time > self.max_time {
+                if state.interaction_time < self.back_time {
+                    InputResult::from(InputState::InProgress(255))
+                } else {
+                    // Ignore the rest of the touch.
+                    state.repeated = true;
+                    state.interaction_time = 0;
+                    InputResult::from(Interaction::Action(Action::Back))
+                }
             } else {
                 state.repeated = true;
                 state.interaction_time = 0;
@@ -102,6 +127,8 @@ impl<R> InputAdapter for SingleTouchAdapter<R> {
 
             if self.debounce_time < time && time < self.max_time && !state.repeated {
                 InputResult::from(Interaction::Navigation(Navigation::Next))
+            } else if time >= self.max_time && !state.repeated {
+                InputResult::from(Interaction::Action(Action::Select))
             } else {
                 // Already interacted before releasing, ignore and reset.
                 state.repeated = false;
@@ -109,10 +136,25 @@ impl<R> InputAdapter for SingleTouchAdapter<R> {
             }
         }
     }
+
+    fn hint(&self, editing: bool) -> &'static str {
+        match (editing, self.back_time > self.max_time) {
+            (true, false) => "tap: change, hold: ok",
+            (true, true) => "tap: change, hold: ok, longer: cancel",
+            (false, false) => "tap: next, hold: ok",
+            (false, true) => "tap: next, hold: ok, longer: back",
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::{
+        test_utils::{menu_with_items, touch},
+        {Menu, MenuStyle},
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
     use crate::interaction::{
         single_touch::SingleTouch, Action, InputAdapter, InputAdapterSource, InputResult,
         InputState, Interaction, Navigation,
@@ -125,6 +167,7 @@ mod test {
             ignore_time: 1,
             debounce_time: 1,
             max_time: 5,
+            back_time: 0,
         }
         .adapter();
 
@@ -203,4 +246,89 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn very_long_press_goes_back() {
+        // accept 5-7 as long press on release, 8- as very long press
+        let controller = SingleTouch {
+            ignore_time: 1,
+            debounce_time: 1,
+            max_time: 5,
+            back_time: 8,
+        }
+        .adapter();
+
+        let expectations: [&[(bool, InputResult<()>)]; 2] = [
+            &[
+                (false, InputState::Idle.into()),
+                (true, InputState::Idle.into()),
+                (true, InputState::InProgress(63).into()),
+                (true, InputState::InProgress(127).into()),
+                (true, InputState::InProgress(191).into()),
+                (true, InputState::InProgress(255).into()),
+                (true, InputState::InProgress(255).into()),
+                (false, Interaction::Action(Action::Select).into()),
+            ],
+            &[
+                (false, InputState::Idle.into()),
+                (true, InputState::Idle.into()),
+                (true, InputState::InProgress(63).into()),
+                (true, InputState::InProgress(127).into()),
+                (true, InputState::InProgress(191).into()),
+                (true, InputState::InProgress(255).into()),
+                (true, InputState::InProgress(255).into()),
+                (true, InputState::InProgress(255).into()),
+                (true, Interaction::Action(Action::Back).into()),
+                (true, InputState::Idle.into()),
+                (false, InputState::Idle.into()),
+            ],
+        ];
+
+        for (row, &inputs) in expectations.iter().enumerate() {
+            let mut controller_state = Default::default();
+
+            for (sample, (input, expectation)) in inputs.iter().enumerate() {
+                let ret = controller.handle_input(&mut controller_state, *input);
+
+                assert_eq!(
+                    ret, *expectation,
+                    "Mismatch at row {}, sample {}",
+                    row, sample
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_touch_enters_and_leaves_submenu() {
+        let style = MenuStyle::new(BinaryColor::On).with_input_adapter(SingleTouch {
+            ignore_time: 1,
+            debounce_time: 1,
+            max_time: 5,
+            back_time: 10,
+        });
+        let mut menu = menu_with_items(style, 1)
+            .add_submenu(
+                Menu::with_style("Settings", style)
+                    .add_item("Item 2", (), |_| 2)
+                    .add_item("Item 3", (), |_| 3),
+                1,
+            )
+            .build();
+        menu.interact(false);
+
+        assert_eq!(touch(|input| menu.interact(input), 2), None);
+        assert_eq!(touch(|input| menu.interact(input), 7), None);
+        assert_eq!(menu.state().depth(), 1);
+
+        assert_eq!(touch(|input| menu.interact(input), 2), None);
+        assert_eq!(menu.selected_value(), 3);
+
+        assert_eq!(touch(|input| menu.interact(input), 12), None);
+        assert_eq!(menu.state().depth(), 0);
+        assert_eq!(menu.selected_value(), 1);
+
+        assert_eq!(touch(|input| menu.interact(input), 7), None);
+        assert_eq!(touch(|input| menu.interact(input), 7), Some(2));
+    }
 }
@@ -1,12 +1,17 @@
 use embedded_graphics::{
-    mono_font::MonoTextStyle,
-    pixelcolor::BinaryColor,
+    pixelcolor::PixelColor,
     prelude::{DrawTarget, Point},
     primitives::Rectangle,
+    text::renderer::TextRenderer,
 };
 use embedded_layout::View;
 
-use crate::items::{Marker, MenuLine, MenuListItem};
+use crate::{
+    dialog::ConfirmDialog,
+    items::{text_width, DrawContext, EditAction, Marker, MenuLine, MenuListItem},
+    marquee::Marquee,
+    MenuTextStyle,
+};
 
 pub trait SelectValue: Sized + Clone + PartialEq {
     /// Transforms the value on interaction
@@ -51,6 +56,14 @@ where
     title_text: T,
     convert: fn(S) -> R,
     value: S,
+    key: Option<u32>,
+    hint: Option<&'static str>,
+    details: Option<&'static str>,
+    confirmation: Option<ConfirmDialog>,
+    popup: bool,
+    /// The index of the highlighted value while the popup picker is open, counted from the
+    /// current value.
+    picking: Option<usize>,
     line: MenuLine,
 }
 
@@ -64,6 +77,12 @@ where
             title_text,
             value,
             convert: |_| (),
+            key: None,
+            hint: None,
+            details: None,
+            confirmation: None,
+            popup: false,
+            picking: None,
             line: MenuLine::empty(),
         }
     }
@@ -79,16 +98,113 @@ where
             convert,
             title_text: self.title_text,
             value: self.value,
+            key: self.key,
+            hint: self.hint,
+            details: self.details,
+            confirmation: self.confirmation,
+            popup: self.popup,
+            picking: self.picking,
             line: self.line,
         }
     }
 
+    /// Sets the key that identifies the item. See [`MenuListItem::key`].
+    pub fn with_key(self, key: u32) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets the hint displayed in the footer while the item is selected. See
+    /// [`MenuListItem::hint`].
+    pub fn with_hint(self, hint: &'static str) -> Self {
+        Self {
+            hint: Some(hint),
+            ..self
+        }
+    }
+
+    /// Sets the description displayed after the item has been selected for a while. See
+    /// [`MenuListItem::details`].
+    pub fn with_detail_text(self, details: &'static str) -> Self {
+        Self {
+            details: Some(details),
+            ..self
+        }
+    }
+
+    /// Asks the user to confirm using `dialog` before the item is interacted with. See
+    /// [`MenuListItem::confirmation`].
+    pub fn with_confirmation(self, dialog: ConfirmDialog) -> Self {
+        Self {
+            confirmation: Some(dialog),
+            ..self
+        }
+    }
+
+    /// Selecting the item opens a popup list of all values instead of changing to the next one.
+    /// Navigation highlights a value, selecting it writes it back to the item and returns it,
+    /// going back closes the list without changes.
+    ///
+    /// The values are listed in the order of [`SelectValue::next`], starting with the current
+    /// value. Use this for values with many variants, e.g. languages or baud rates.
+    pub fn with_popup_picker(self) -> Self {
+        Self {
+            popup: true,
+            ..self
+        }
+    }
+
+    /// Reserves `lines` lines of text for the item. A title that does not fit on a single line is
+    /// word-wrapped.
+    pub fn with_lines(self, lines: u32) -> Self {
+        Self {
+            line: self.line.with_lines(lines),
+            ..self
+        }
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> &S {
+        &self.value
+    }
+
+    /// Returns the number of values `next` cycles through.
+    fn value_count(&self) -> usize {
+        let mut current = self.value.clone();
+        let mut count = 1;
+
+        loop {
+            current.next();
+            if current == self.value {
+                return count;
+            }
+            count += 1;
+        }
+    }
+
+    /// Returns the value `n` calls to `next` after the current one.
+    fn nth_value(&self, n: usize) -> S {
+        let mut value = self.value.clone();
+        for _ in 0..n {
+            value.next();
+        }
+        value
+    }
+
     /// Make the item selectable or not
     pub fn selectable<const SELECTABLE2: bool>(self) -> MenuItem<T, R, S, SELECTABLE2> {
         MenuItem {
             convert: self.convert,
             title_text: self.title_text,
             value: self.value,
+            key: self.key,
+            hint: self.hint,
+            details: self.details,
+            confirmation: self.confirmation,
+            popup: self.popup,
+            picking: self.picking,
             line: self.line,
         }
     }
@@ -101,10 +217,11 @@ where
 {
 }
 
-impl<T, R, S, const SELECTABLE: bool> MenuListItem<R> for MenuItem<T, R, S, SELECTABLE>
+impl<T, R, S, C, const SELECTABLE: bool> MenuListItem<R, C> for MenuItem<T, R, S, SELECTABLE>
 where
     T: AsRef<str>,
     S: SelectValue,
+    C: PixelColor,
 {
     fn value_of(&self) -> R {
         (self.convert)(self.value.clone())
@@ -112,18 +229,84 @@ where
 
     fn interact(&mut self) -> R {
         self.value.next();
-        self.value_of()
+        (self.convert)(self.value.clone())
     }
 
     fn selectable(&self) -> bool {
         SELECTABLE
     }
 
-    fn set_style(&mut self, text_style: &MonoTextStyle<'_, BinaryColor>) {
+    fn key(&self) -> Option<u32> {
+        self.key
+    }
+
+    fn hint(&self) -> Option<&'static str> {
+        self.hint
+    }
+
+    fn details(&self) -> Option<&'static str> {
+        self.details
+    }
+
+    fn confirmation(&self) -> Option<ConfirmDialog> {
+        self.confirmation
+    }
+
+    fn is_editing(&self) -> bool {
+        self.picking.is_some()
+    }
+
+    fn begin_edit(&mut self) -> bool {
+        if self.popup {
+            self.picking = Some(0);
+        }
+        self.popup
+    }
+
+    fn edit(&mut self, action: EditAction) -> Option<R> {
+        let picking = self.picking?;
+
+        match action {
+            EditAction::Navigate(navigation) => {
+                let count = self.value_count();
+                self.picking = Some(navigation.calculate_selection(picking, count, |_| true));
+                None
+            }
+            EditAction::Select => {
+                self.value = self.nth_value(picking);
+                self.picking = None;
+                Some((self.convert)(self.value.clone()))
+            }
+            EditAction::Cancel => {
+                self.picking = None;
+                None
+            }
+        }
+    }
+
+    fn picker(&self, option: &mut dyn FnMut(&str, bool)) -> bool {
+        let Some(picking) = self.picking else {
+            return false;
+        };
+
+        let mut current = self.value.clone();
+        let mut idx = 0;
+        loop {
+            option(current.marker(), idx == picking);
+
+            current.next();
+            idx += 1;
+            if current == self.value {
+                return true;
+            }
+        }
+    }
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
         let mut current = self.value.clone();
         let mut longest = self.value.clone();
 
-        let mut longest_len = longest.marker().len();
+        let mut longest_width = text_width(longest.marker(), text_style);
 
         loop {
             current.next();
@@ -131,30 +314,57 @@ where
                 break;
             }
 
-            if current.marker().len() > longest_len {
+            let width = text_width(current.marker(), text_style);
+            if width > longest_width {
                 longest = current.clone();
-                longest_len = longest.marker().len();
+                longest_width = width;
             }
         }
 
-        self.line = MenuLine::new(longest.marker(), text_style);
+        self.line = MenuLine::new(longest.marker(), text_style).with_lines(self.line.lines());
     }
 
-    fn draw_styled<D>(
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_with_context(text_style, &DrawContext::default(), display)
+    }
+
+    fn draw_with_context<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
     {
-        self.line.draw_styled(
+        self.line.draw_with_context(
             self.title_text.as_ref(),
             self.value.marker(),
             text_style,
+            context,
             display,
         )
     }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.line.marquee_overflow(
+            self.title_text.as_ref(),
+            self.value.marker(),
+            text_style,
+            marquee,
+            width,
+        )
+    }
 }
 
 impl<T, R, S, const SELECTABLE: bool> View for MenuItem<T, R, S, SELECTABLE>
@@ -173,12 +383,19 @@ where
 
 #[cfg(test)]
 mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        test_utils::{assert_redrawn, mock_display},
+        Menu,
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Point};
+
     #[test]
     fn interaction_selects_next_value_and_returns_converted() {
         use super::*;
-        use crate::items::MenuListItem;
+        use crate::items::{monochrome, MenuListItem};
 
-        let mut item = MenuItem::new("title", false).with_value_converter(|b| b as u8);
+        let mut item = monochrome(MenuItem::new("title", false).with_value_converter(|b| b as u8));
 
         assert_eq!(item.value_of(), 0);
 
@@ -188,4 +405,115 @@ mod test {
         assert_eq!(item.interact(), 0);
         assert_eq!(item.value_of(), 0);
     }
+
+    #[test]
+    fn popup_picker_writes_back_highlighted_value() {
+        use super::*;
+        use crate::{
+            interaction::Navigation,
+            items::{monochrome, MenuListItem},
+        };
+
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        enum Baud {
+            B9600,
+            B57600,
+            B115200,
+        }
+
+        impl SelectValue for Baud {
+            fn next(&mut self) {
+                *self = match self {
+                    Self::B9600 => Self::B57600,
+                    Self::B57600 => Self::B115200,
+                    Self::B115200 => Self::B9600,
+                }
+            }
+
+            fn marker(&self) -> &str {
+                match self {
+                    Self::B9600 => "9600",
+                    Self::B57600 => "57600",
+                    Self::B115200 => "115200",
+                }
+            }
+        }
+
+        let mut item = monochrome(
+            MenuItem::new("Baud rate", Baud::B57600)
+                .with_popup_picker()
+                .with_value_converter(|b| b),
+        );
+
+        assert!(!item.picker(&mut |_, _| {}));
+        assert!(item.begin_edit());
+
+        item.edit(EditAction::Navigate(Navigation::Next));
+        let mut count = 0;
+        let mut highlighted = None;
+        assert!(item.picker(&mut |option, is_highlighted| {
+            if is_highlighted {
+                highlighted = Some((count, option == "115200"));
+            }
+            count += 1;
+        }));
+        assert_eq!(count, 3);
+        assert_eq!(highlighted, Some((1, true)));
+
+        // Cancelling keeps the value, selecting writes the highlighted one back.
+        assert_eq!(item.edit(EditAction::Cancel), None);
+        assert_eq!(item.value_of(), Baud::B57600);
+
+        item.begin_edit();
+        item.edit(EditAction::Navigate(Navigation::Previous));
+        assert_eq!(item.edit(EditAction::Select), Some(Baud::B9600));
+        assert!(!item.is_editing());
+        assert_eq!(item.value_of(), Baud::B9600);
+    }
+
+    #[test]
+    fn popup_picker_is_drawn_over_the_list() {
+        use crate::items::MenuItem;
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_menu_item(
+                MenuItem::new("Enabled", false)
+                    .with_popup_picker()
+                    .with_value_converter(|enabled| enabled as i32),
+            )
+            .build();
+
+        let mut display = mock_display();
+        menu.draw_partial(&mut display).unwrap();
+
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let (highlighted, picker_area) = menu.last_drawn.unwrap().picker.unwrap();
+        assert_eq!(highlighted, 0);
+        assert_redrawn(&regions, picker_area);
+
+        // The highlighted option is filled up to the right edge of the picker, inside its border.
+        let row_end = |display: &MockDisplay<BinaryColor>, row: i32| {
+            let x = picker_area.bottom_right().unwrap().x - 2;
+            display.get_pixel(Point::new(x, picker_area.top_left.y + 2 + row * 10))
+        };
+        assert_eq!(
+            display.get_pixel(picker_area.top_left),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(row_end(&display, 0), Some(BinaryColor::On));
+        assert_eq!(row_end(&display, 1), Some(BinaryColor::Off));
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.last_drawn.unwrap().picker.unwrap().0, 1);
+        assert_eq!(row_end(&display, 0), Some(BinaryColor::Off));
+        assert_eq!(row_end(&display, 1), Some(BinaryColor::On));
+
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(1));
+        let regions = menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.last_drawn.unwrap().picker, None);
+        assert_redrawn(&regions, picker_area);
+        assert_eq!(menu.selected_value(), 1);
+    }
 }
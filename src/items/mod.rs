@@ -1,29 +1,55 @@
 pub mod menu_item;
+pub mod numeric;
+pub mod radio;
+pub mod submenu;
+pub mod text;
 
 pub use menu_item::MenuItem;
+pub use numeric::NumericItem;
+pub use radio::RadioGroup;
+pub use submenu::SubMenu;
+pub use text::TextItem;
 
 use embedded_graphics::{
-    draw_target::DrawTarget,
-    mono_font::MonoTextStyle,
-    pixelcolor::BinaryColor,
+    draw_target::{DrawTarget, DrawTargetExt},
+    pixelcolor::PixelColor,
     prelude::{Point, Size},
     primitives::Rectangle,
-    text::{renderer::TextRenderer, Baseline},
+    text::{renderer::TextRenderer, Baseline, Text},
     Drawable,
 };
 use embedded_layout::prelude::*;
 use embedded_text::{alignment::HorizontalAlignment, style::TextBoxStyleBuilder, TextBox};
 
+use crate::{
+    collection::{LevelVisitor, LevelVisitorMut},
+    dialog::ConfirmDialog,
+    interaction::Navigation,
+    marquee::{Marquee, TitleOverflow},
+    MenuTextStyle,
+};
+
 /// Marker trait necessary to avoid a "conflicting implementations" error.
 pub trait Marker {}
 
-pub trait MenuListItem<R>: Marker + View {
+/// Input forwarded to an item that is being edited.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EditAction {
+    /// Change the edited value.
+    Navigate(Navigation),
+    /// Confirm the edit.
+    Select,
+    /// Discard the edit.
+    Cancel,
+}
+
+pub trait MenuListItem<R, C: PixelColor>: Marker + View {
     /// Returns the value of the selected item, without interacting with it.
     fn value_of(&self) -> R;
 
     fn interact(&mut self) -> R;
 
-    fn set_style(&mut self, text_style: &MonoTextStyle<'_, BinaryColor>);
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F);
 
     /// Returns whether the list item is selectable.
     ///
@@ -33,35 +59,224 @@ pub trait MenuListItem<R>: Marker + View {
         true
     }
 
-    fn draw_styled<D>(
+    /// Returns the key that identifies the list item.
+    ///
+    /// The menu remembers the key of the selected item, so that rebuilding the menu with a saved
+    /// [`MenuState`] selects the same item even if its position changed.
+    ///
+    /// [`MenuState`]: crate::MenuState
+    fn key(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns the hint displayed in the footer while the list item is selected, e.g.
+    /// `"hold: toggle"`.
+    ///
+    /// If this returns `None`, which is the default, the footer displays the hint of the input
+    /// adapter. See [`MenuStyle::with_footer`].
+    ///
+    /// [`MenuStyle::with_footer`]: crate::MenuStyle::with_footer
+    fn hint(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the dialog that asks the user to confirm the interaction with the list item.
+    ///
+    /// If this returns a dialog, selecting the item opens it, and the item is only interacted
+    /// with after the user confirmed. The default is `None`.
+    fn confirmation(&self) -> Option<ConfirmDialog> {
+        None
+    }
+
+    /// Returns a description of the list item, e.g. on-device help.
+    ///
+    /// The description is displayed after the item has been selected for a number of updates, in
+    /// the footer if it is displayed, or in a popup otherwise. See
+    /// [`MenuStyle::with_details_delay`].
+    ///
+    /// [`MenuStyle::with_details_delay`]: crate::MenuStyle::with_details_delay
+    fn details(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns whether the list item is being edited.
+    ///
+    /// While an item is being edited, the menu forwards user input to [`MenuListItem::edit`]
+    /// instead of changing the selection.
+    fn is_editing(&self) -> bool {
+        false
+    }
+
+    /// Called when the user selects the list item. Returns whether the item entered editing mode.
+    ///
+    /// If this returns false, which is the default, the menu calls [`MenuListItem::interact`]
+    /// instead.
+    fn begin_edit(&mut self) -> bool {
+        false
+    }
+
+    /// Handles user input while the list item is being edited.
+    ///
+    /// Returns a value when editing is finished and the menu should return it to the user.
+    fn edit(&mut self, _action: EditAction) -> Option<R> {
+        None
+    }
+
+    /// Lists the options of the popup picker opened by the list item, e.g. by
+    /// [`MenuItem::with_popup_picker`].
+    ///
+    /// While the picker is open, this calls `option` with the label of each option and whether
+    /// the option is highlighted, and returns true. Items without a picker should return false,
+    /// which is the default.
+    fn picker(&self, _option: &mut dyn FnMut(&str, bool)) -> bool {
+        false
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>;
+
+    /// Draws the list item. `context` tells whether the item is selected, and how a title that
+    /// does not fit should be displayed.
+    ///
+    /// The default implementation ignores `context` and calls [`MenuListItem::draw_styled`].
+    fn draw_with_context<F, D>(
         &self,
-        text_style: &MonoTextStyle<'static, BinaryColor>,
+        text_style: &F,
+        _context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>;
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_styled(text_style, display)
+    }
+
+    /// Returns how many pixels the text scrolled by `marquee` is wider than the space available
+    /// to it, if the item is drawn `width` pixels wide.
+    ///
+    /// The menu keeps animating while the selected item overflows. Items that don't scroll their
+    /// text should return 0, which is the default.
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        _text_style: &F,
+        _marquee: &Marquee,
+        _width: u32,
+    ) -> u32 {
+        0
+    }
+
+    /// Runs `visitor` on the submenu opened by this item. The elements of `path` select further
+    /// nested submenus.
+    ///
+    /// Items that don't open a submenu should return `None`, which is the default.
+    fn visit_submenu<V>(&self, _path: &[usize], _visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        None
+    }
+
+    /// Runs `visitor` on the submenu opened by this item. The elements of `path` select further
+    /// nested submenus.
+    ///
+    /// Items that don't open a submenu should return `None`, which is the default.
+    fn visit_submenu_mut<V>(&mut self, _path: &[usize], _visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        None
+    }
+}
+
+/// Describes how the items of a menu list are drawn. See [`MenuListItem::draw_with_context`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DrawContext {
+    selected: Option<usize>,
+    overflow: TitleOverflow,
+    marquee: Option<Marquee>,
+    ticks: u32,
+}
+
+impl Default for DrawContext {
+    fn default() -> Self {
+        Self::new(None, TitleOverflow::Clip, None, 0)
+    }
+}
+
+impl DrawContext {
+    pub(crate) const fn new(
+        selected: Option<usize>,
+        overflow: TitleOverflow,
+        marquee: Option<Marquee>,
+        ticks: u32,
+    ) -> Self {
+        Self {
+            selected,
+            overflow,
+            marquee,
+            ticks,
+        }
+    }
+
+    /// Returns the context of the `nth` item of the collection this context was passed to.
+    pub fn nth(&self, nth: usize) -> Self {
+        Self {
+            selected: self.selected.and_then(|selected| selected.checked_sub(nth)),
+            ..*self
+        }
+    }
+
+    /// Returns whether the item this context was passed to is selected.
+    pub fn is_selected(&self) -> bool {
+        self.selected == Some(0)
+    }
+
+    /// Returns how titles that don't fit are displayed, if they are not scrolled.
+    pub fn overflow(&self) -> TitleOverflow {
+        self.overflow
+    }
+
+    /// Returns the marquee that scrolls the item's text, if the item is selected.
+    pub fn marquee(&self) -> Option<&Marquee> {
+        self.marquee.as_ref().filter(|_| self.is_selected())
+    }
+
+    /// Returns how many pixels the item's text is currently scrolled by, if the text is
+    /// `overflow` pixels wider than its area. Returns `None` if the text does not scroll.
+    pub fn marquee_offset(&self, overflow: u32) -> Option<u32> {
+        self.marquee()
+            .map(|marquee| marquee.offset(self.ticks, overflow))
+    }
 }
 
 /// Helper struct to draw a menu line that has a title and some additional marker.
 pub struct MenuLine {
     bounds: Rectangle,
     value_width: u32,
+    lines: u32,
 }
 
-impl MenuLine {
-    pub fn new(longest_value: &str, text_style: &MonoTextStyle<'_, BinaryColor>) -> Self {
-        let value_width = text_style
-            .measure_string(longest_value, Point::zero(), Baseline::Top)
-            .bounding_box
-            .size
-            .width;
+/// Returns the width of `text` when rendered using `text_style`.
+pub(crate) fn text_width(text: &str, text_style: &impl TextRenderer) -> u32 {
+    text_style
+        .measure_string(text, Point::zero(), Baseline::Top)
+        .bounding_box
+        .size
+        .width
+}
 
+impl MenuLine {
+    pub fn new(longest_value: &str, text_style: &impl TextRenderer) -> Self {
         MenuLine {
             bounds: Rectangle::new(
                 Point::zero(),
-                Size::new(1, text_style.font.character_size.height - 1),
+                Size::new(1, text_style.line_height().saturating_sub(1)),
             ),
-            value_width,
+            value_width: text_width(longest_value, text_style),
+            lines: 1,
         }
     }
 
@@ -69,18 +284,61 @@ impl MenuLine {
         MenuLine {
             bounds: Rectangle::new(Point::zero(), Size::new(1, 0)),
             value_width: 0,
+            lines: 1,
         }
     }
 
-    pub fn draw_styled<D>(
+    /// Makes the line `lines` lines of text tall. A title that does not fit on a single line is
+    /// word-wrapped, instead of being scrolled or shortened.
+    pub fn with_lines(mut self, lines: u32) -> Self {
+        let lines = lines.max(1);
+        let line_height = (self.bounds.size.height + 1) / self.lines;
+
+        self.bounds.size.height = (line_height * lines).saturating_sub(1);
+        self.lines = lines;
+        self
+    }
+
+    /// Returns the number of lines of text the line is tall.
+    pub fn lines(&self) -> u32 {
+        self.lines
+    }
+
+    pub fn draw_styled<C, F, D>(
+        &self,
+        title: &str,
+        value_text: &str,
+        text_style: &F,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_with_context(
+            title,
+            value_text,
+            text_style,
+            &DrawContext::default(),
+            display,
+        )
+    }
+
+    /// Draws the line like [`MenuLine::draw_styled`], scrolling or shortening a title that does
+    /// not fit as described by `context`.
+    pub fn draw_with_context<C, F, D>(
         &self,
         title: &str,
         value_text: &str,
-        text_style: &MonoTextStyle<'static, BinaryColor>, // TODO: allow non-mono fonts
+        text_style: &F,
+        context: &DrawContext,
         display: &mut D,
     ) -> Result<(), D::Error>
     where
-        D: DrawTarget<Color = BinaryColor>,
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
     {
         let display_area = display.bounding_box();
 
@@ -88,14 +346,100 @@ impl MenuLine {
             return Ok(());
         }
 
-        let mut text_bounds = Rectangle::new(
+        let line_area = Rectangle::new(
             self.bounds.top_left,
             Size::new(display_area.size.width, self.bounds.size.height + 1),
         );
+        let mut title_area = line_area;
+        title_area.size.width = title_area.size.width.saturating_sub(self.value_width);
+
+        let title_overflows =
+            self.lines == 1 && text_width(title, text_style) > title_area.size.width;
+
+        if title_overflows {
+            if let Some(marquee) = context.marquee() {
+                let overflow = self.marquee_overflow(
+                    title,
+                    value_text,
+                    text_style,
+                    marquee,
+                    line_area.size.width,
+                );
+                let offset = context.marquee_offset(overflow).unwrap_or(0) as i32;
+                let position = title_area.top_left - Point::new(offset, 0);
+
+                if marquee.scrolls_value() {
+                    let mut display = display.clipped(&line_area);
+                    let next = Text::with_baseline(title, position, *text_style, Baseline::Top)
+                        .draw(&mut display)?;
+                    let gap = text_width(" ", text_style) as i32;
+                    Text::with_baseline(
+                        value_text,
+                        Point::new(next.x + gap, position.y),
+                        *text_style,
+                        Baseline::Top,
+                    )
+                    .draw(&mut display)?;
+                } else {
+                    self.draw_value(value_text, text_style, line_area, display)?;
+                    Text::with_baseline(title, position, *text_style, Baseline::Top)
+                        .draw(&mut display.clipped(&title_area))?;
+                }
+
+                return Ok(());
+            }
+        }
+
+        self.draw_value(value_text, text_style, line_area, display)?;
+
+        if title_overflows && context.overflow() == TitleOverflow::Ellipsis {
+            const ELLIPSIS: &str = "...";
+            let available = title_area
+                .size
+                .width
+                .saturating_sub(text_width(ELLIPSIS, text_style));
+
+            // Keep the longest prefix that fits next to the ellipsis.
+            let end = title
+                .char_indices()
+                .map(|(idx, _)| idx)
+                .skip(1)
+                .chain(core::iter::once(title.len()))
+                .take_while(|&end| text_width(&title[..end], text_style) <= available)
+                .last()
+                .unwrap_or(0);
+
+            let mut display = display.clipped(&title_area);
+            let next = Text::with_baseline(
+                &title[..end],
+                title_area.top_left,
+                *text_style,
+                Baseline::Top,
+            )
+            .draw(&mut display)?;
+            Text::with_baseline(ELLIPSIS, next, *text_style, Baseline::Top).draw(&mut display)?;
+        } else {
+            TextBox::new(title, title_area, *text_style).draw(display)?;
+        }
+
+        Ok(())
+    }
 
+    fn draw_value<C, F, D>(
+        &self,
+        value_text: &str,
+        text_style: &F,
+        line_area: Rectangle,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
         TextBox::with_textbox_style(
             value_text,
-            text_bounds,
+            line_area,
             *text_style,
             TextBoxStyleBuilder::new()
                 .alignment(HorizontalAlignment::Right)
@@ -103,11 +447,34 @@ impl MenuLine {
         )
         .draw(display)?;
 
-        text_bounds.size.width -= self.value_width;
-        TextBox::new(title, text_bounds, *text_style).draw(display)?;
-
         Ok(())
     }
+
+    /// Returns how many pixels the text scrolled by `marquee` is wider than its area, if the line
+    /// is drawn `width` pixels wide. Returns 0 if the title fits next to the value, or if the
+    /// title is wrapped.
+    pub fn marquee_overflow(
+        &self,
+        title: &str,
+        value_text: &str,
+        text_style: &impl TextRenderer,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        let title_width = text_width(title, text_style);
+        let title_area_width = width.saturating_sub(self.value_width);
+        if self.lines > 1 || title_width <= title_area_width {
+            return 0;
+        }
+
+        if marquee.scrolls_value() {
+            let scrolled_width =
+                title_width + text_width(" ", text_style) + text_width(value_text, text_style);
+            scrolled_width.saturating_sub(width)
+        } else {
+            title_width - title_area_width
+        }
+    }
 }
 
 impl View for MenuLine {
@@ -119,3 +486,89 @@ impl View for MenuLine {
         self.bounds
     }
 }
+
+/// Fixes the color type of an item, so that tests can call its [`MenuListItem`] methods.
+#[cfg(test)]
+pub(crate) fn monochrome<R>(
+    item: impl MenuListItem<R, embedded_graphics::pixelcolor::BinaryColor>,
+) -> impl MenuListItem<R, embedded_graphics::pixelcolor::BinaryColor> {
+    item
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Interaction, Navigation},
+        test_utils::{menu_with_items, mock_display, Proportional},
+        Menu, MenuStyle,
+    };
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{Dimensions, Point},
+        Drawable,
+    };
+
+    #[test]
+    fn proportional_text_style_layout() {
+        let mut menu = Menu::with_style(
+            "Root",
+            MenuStyle::new(BinaryColor::On)
+                .with_text_style(Proportional::new(8))
+                .with_title_text_style(Proportional::new(13)),
+        )
+        .add_item("Item", "W", |_| 0)
+        .add_item("Other", "Wi", |_| 1)
+        .build();
+
+        let mut display = mock_display();
+        menu.update(&display);
+        menu.draw(&mut display).unwrap();
+
+        // The header is as tall as the title font, followed by the separator.
+        let header = menu.header_area("Root", display.bounding_box()).unwrap();
+        assert_eq!(header.size.height, 13 + 1);
+        assert_eq!(display.get_pixel(Point::new(32, 13)), Some(BinaryColor::On));
+        assert_ne!(display.get_pixel(Point::new(32, 12)), Some(BinaryColor::On));
+
+        // The value is measured using the proportional font, it's 6 + 2 pixels wide instead of
+        // 2 * 6, and is aligned to the right edge.
+        let row = 14 + 7 + 3;
+        let on = |x| display.get_pixel(Point::new(x, row)) == Some(BinaryColor::On);
+        assert!(!on(55));
+        assert!((56..61).all(on));
+        assert!(!on(61));
+        assert!(on(62));
+        assert!(!on(63));
+    }
+
+    #[test]
+    fn wrapped_items_cover_all_their_lines() {
+        use crate::items::MenuItem;
+        use embedded_graphics::mono_font::ascii::FONT_6X10;
+
+        let mut menu = menu_with_items(MenuStyle::new(BinaryColor::On), 3)
+            .add_menu_item(
+                MenuItem::new("Erase all logs and restart device", ())
+                    .with_value_converter(|_| 3)
+                    .with_lines(3),
+            )
+            .build();
+
+        let mut display = mock_display();
+
+        menu.interact(Interaction::Navigation(Navigation::End));
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+
+        let snapshot = menu.last_drawn.unwrap();
+        let line_height = FONT_6X10.character_size.height;
+
+        // The list scrolled so that all three lines of the last item are visible.
+        assert!(snapshot.list_offset > 0);
+        assert!(snapshot.selection_area.size.height >= 3 * line_height - 1);
+        assert_eq!(
+            snapshot.selection_area.bottom_right().unwrap().y,
+            display.bounding_box().bottom_right().unwrap().y
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point},
+    primitives::Rectangle,
+    text::renderer::TextRenderer,
+};
+use embedded_layout::View;
+
+use crate::{
+    buffer::TextBuffer,
+    interaction::Navigation,
+    items::{text_width, DrawContext, EditAction, Marker, MenuLine, MenuListItem},
+    marquee::Marquee,
+    MenuTextStyle,
+};
+
+/// Integer types that can be edited by [`NumericItem`].
+pub trait NumericValue: Copy + PartialOrd {
+    fn to_i128(self) -> i128;
+
+    /// Converts `value` back, saturating at the type's limits.
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_numeric_value {
+    ($($ty:ty),*) => {
+        $(
+            impl NumericValue for $ty {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value.clamp(Self::MIN as i128, Self::MAX as i128) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+type ValueBuffer = TextBuffer<48>;
+
+/// The largest number of decimals, `10^38` is the largest power of ten that fits a `u128`.
+const MAX_DECIMALS: u8 = 38;
+
+/// A menu item that edits a number between `min` and `max`.
+///
+/// Selecting the item enters editing mode, in which navigation changes the value by `step`
+/// instead of moving the selection. Selecting the item again confirms the new value, going back
+/// restores the original one.
+///
+/// Fixed-point values are stored as integers and displayed with the given number of decimals,
+/// e.g. a value of `125` with one decimal is displayed as `12.5`.
+///
+/// Wrapping navigation, e.g. [`Navigation::Next`] or [`Navigation::ForwardWrapping`], wraps the
+/// value around from `max` to `min` and back, so input adapters that only ever move forward can
+/// reach every value. Other navigation stops at `min` and `max`.
+pub struct NumericItem<T, R, N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+{
+    title_text: T,
+    convert: fn(N) -> R,
+    value: N,
+    original: N,
+    min: N,
+    max: N,
+    step: N,
+    decimals: u8,
+    editing: bool,
+    key: Option<u32>,
+    hint: Option<&'static str>,
+    details: Option<&'static str>,
+    line: MenuLine,
+}
+
+impl<T, N> NumericItem<T, (), N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+{
+    pub fn new(title_text: T, value: N, min: N, max: N) -> Self {
+        // Accept the bounds in either order, `clamp` panics if they are reversed.
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        let value = N::from_i128(value.to_i128().clamp(min.to_i128(), max.to_i128()));
+
+        Self {
+            title_text,
+            convert: |_| (),
+            value,
+            original: value,
+            min,
+            max,
+            step: N::from_i128(1),
+            decimals: 0,
+            editing: false,
+            key: None,
+            hint: None,
+            details: None,
+            line: MenuLine::empty(),
+        }
+    }
+}
+
+impl<T, R, N> NumericItem<T, R, N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+{
+    pub fn with_value_converter<R2>(self, convert: fn(N) -> R2) -> NumericItem<T, R2, N> {
+        NumericItem {
+            convert,
+            title_text: self.title_text,
+            value: self.value,
+            original: self.original,
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            decimals: self.decimals,
+            editing: self.editing,
+            key: self.key,
+            hint: self.hint,
+            details: self.details,
+            line: self.line,
+        }
+    }
+
+    /// Sets the key that identifies the item. See [`MenuListItem::key`].
+    pub fn with_key(self, key: u32) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets the hint displayed in the footer while the item is selected. See
+    /// [`MenuListItem::hint`].
+    pub fn with_hint(self, hint: &'static str) -> Self {
+        Self {
+            hint: Some(hint),
+            ..self
+        }
+    }
+
+    /// Sets the description displayed after the item has been selected for a while. See
+    /// [`MenuListItem::details`].
+    pub fn with_detail_text(self, details: &'static str) -> Self {
+        Self {
+            details: Some(details),
+            ..self
+        }
+    }
+
+    /// Reserves `lines` lines of text for the item. A title that does not fit on a single line is
+    /// word-wrapped.
+    pub fn with_lines(self, lines: u32) -> Self {
+        Self {
+            line: self.line.with_lines(lines),
+            ..self
+        }
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> N {
+        self.value
+    }
+
+    /// Sets the amount a single navigation step changes the value by.
+    pub fn with_step(self, step: N) -> Self {
+        Self { step, ..self }
+    }
+
+    /// Sets the number of decimal digits used to display a fixed-point value, at most 38.
+    pub fn with_decimals(self, decimals: u8) -> Self {
+        Self {
+            decimals: decimals.min(MAX_DECIMALS),
+            ..self
+        }
+    }
+
+    fn step_by(&mut self, steps: i128) {
+        let value = self.value.to_i128() + steps.saturating_mul(self.step.to_i128());
+
+        self.set_value(value);
+    }
+
+    fn wrap_by(&mut self, steps: i128) {
+        let min = self.min.to_i128();
+        let step = self.step.to_i128();
+        if step <= 0 {
+            return self.step_by(steps);
+        }
+
+        // The number of values reachable from `min`, including `min` itself.
+        let positions = (self.max.to_i128() - min) / step + 1;
+        let position = (self.value.to_i128() - min) / step;
+        let position = (position + steps % positions).rem_euclid(positions);
+
+        self.set_value(min + position * step);
+    }
+
+    fn set_value(&mut self, value: i128) {
+        let value = value.clamp(self.min.to_i128(), self.max.to_i128());
+        self.value = N::from_i128(value);
+    }
+
+    fn navigate(&mut self, navigation: Navigation) {
+        let steps = |n: usize| i128::try_from(n).unwrap_or(i128::MAX);
+
+        match navigation {
+            Navigation::Next => self.wrap_by(1),
+            Navigation::Previous => self.wrap_by(-1),
+            Navigation::ForwardWrapping(n) => self.wrap_by(steps(n)),
+            Navigation::BackwardWrapping(n) => self.wrap_by(-steps(n)),
+            Navigation::Forward(n) => self.step_by(steps(n)),
+            Navigation::Backward(n) => self.step_by(-steps(n)),
+            Navigation::Beginning => self.value = self.min,
+            Navigation::End => self.value = self.max,
+            Navigation::JumpTo(n) => self.set_value(
+                self.min
+                    .to_i128()
+                    .saturating_add(steps(n).saturating_mul(self.step.to_i128())),
+            ),
+        }
+    }
+
+    fn format(&self, value: N, editing: bool) -> ValueBuffer {
+        let value = value.to_i128();
+
+        let sign = if value < 0 { "-" } else { "" };
+        let (open, close) = if editing { ("<", ">") } else { ("", "") };
+
+        if self.decimals == 0 {
+            ValueBuffer::format(format_args!("{open}{sign}{}{close}", value.unsigned_abs()))
+        } else {
+            let scale = 10u128.pow(self.decimals as u32);
+            let value = value.unsigned_abs();
+
+            ValueBuffer::format(format_args!(
+                "{open}{sign}{}.{:0width$}{close}",
+                value / scale,
+                value % scale,
+                width = self.decimals as usize,
+            ))
+        }
+    }
+}
+
+impl<T, R, N> Marker for NumericItem<T, R, N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+{
+}
+
+impl<T, R, N, C> MenuListItem<R, C> for NumericItem<T, R, N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+    C: PixelColor,
+{
+    fn value_of(&self) -> R {
+        (self.convert)(self.value)
+    }
+
+    fn interact(&mut self) -> R {
+        (self.convert)(self.value)
+    }
+
+    fn key(&self) -> Option<u32> {
+        self.key
+    }
+
+    fn hint(&self) -> Option<&'static str> {
+        self.hint
+    }
+
+    fn details(&self) -> Option<&'static str> {
+        self.details
+    }
+
+    fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    fn begin_edit(&mut self) -> bool {
+        self.original = self.value;
+        self.editing = true;
+        true
+    }
+
+    fn edit(&mut self, action: EditAction) -> Option<R> {
+        match action {
+            EditAction::Navigate(navigation) => {
+                self.navigate(navigation);
+                None
+            }
+            EditAction::Select => {
+                self.editing = false;
+                Some((self.convert)(self.value))
+            }
+            EditAction::Cancel => {
+                self.editing = false;
+                self.value = self.original;
+                None
+            }
+        }
+    }
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
+        let min = self.format(self.min, true);
+        let max = self.format(self.max, true);
+
+        let longest = if text_width(min.as_str(), text_style) > text_width(max.as_str(), text_style)
+        {
+            min
+        } else {
+            max
+        };
+
+        self.line = MenuLine::new(longest.as_str(), text_style).with_lines(self.line.lines());
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_with_context(text_style, &DrawContext::default(), display)
+    }
+
+    fn draw_with_context<F, D>(
+        &self,
+        text_style: &F,
+        context: &DrawContext,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.line.draw_with_context(
+            self.title_text.as_ref(),
+            self.format(self.value, self.editing).as_str(),
+            text_style,
+            context,
+            display,
+        )
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.line.marquee_overflow(
+            self.title_text.as_ref(),
+            self.format(self.value, self.editing).as_str(),
+            text_style,
+            marquee,
+            width,
+        )
+    }
+}
+
+impl<T, R, N> View for NumericItem<T, R, N>
+where
+    T: AsRef<str>,
+    N: NumericValue,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.line.translate_mut(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.line.bounds()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::single_touch::SingleTouch,
+        interaction::{Action, Interaction},
+        test_utils::touch,
+        {Menu, MenuStyle},
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    use super::*;
+    use crate::items::monochrome;
+
+    #[test]
+    fn editing_changes_value_within_bounds() {
+        let mut item = monochrome(
+            NumericItem::new("Volume", 50u8, 0, 100)
+                .with_step(10)
+                .with_value_converter(|v| v),
+        );
+
+        assert!(item.begin_edit());
+        assert!(item.is_editing());
+
+        assert_eq!(item.edit(EditAction::Navigate(Navigation::Next)), None);
+        assert_eq!(item.value_of(), 60);
+
+        item.edit(EditAction::Navigate(Navigation::Forward(100)));
+        assert_eq!(item.value_of(), 100);
+
+        item.edit(EditAction::Navigate(Navigation::Backward(3)));
+        assert_eq!(item.value_of(), 70);
+
+        item.edit(EditAction::Navigate(Navigation::Beginning));
+        assert_eq!(item.value_of(), 0);
+
+        item.edit(EditAction::Navigate(Navigation::Backward(1)));
+        assert_eq!(item.value_of(), 0);
+
+        assert_eq!(item.edit(EditAction::Select), Some(0));
+        assert!(!item.is_editing());
+    }
+
+    #[test]
+    fn cancelling_restores_value() {
+        let mut item =
+            monochrome(NumericItem::new("Offset", -5i32, -10, 10).with_value_converter(|v| v));
+
+        item.begin_edit();
+        item.edit(EditAction::Navigate(Navigation::Forward(8)));
+        assert_eq!(item.value_of(), 3);
+
+        assert_eq!(item.edit(EditAction::Cancel), None);
+        assert!(!item.is_editing());
+        assert_eq!(item.value_of(), -5);
+    }
+
+    #[test]
+    fn fixed_point_formatting() {
+        let item = NumericItem::new("Contrast", -125i16, -200, 200).with_decimals(2);
+
+        assert_eq!(item.format(-125, false).as_str(), "-1.25");
+        assert_eq!(item.format(5, true).as_str(), "<0.05>");
+        assert_eq!(item.format(200, false).as_str(), "2.00");
+    }
+
+    #[test]
+    fn decimals_are_limited() {
+        let item = NumericItem::new("Tiny", 0i64, i64::MIN, i64::MAX).with_decimals(255);
+
+        assert_eq!(item.decimals, MAX_DECIMALS);
+        assert_eq!(
+            item.format(i64::MIN, false).as_str(),
+            "-0.00000000000000000009223372036854775808"
+        );
+    }
+
+    #[test]
+    fn wrapping_navigation_wraps_around() {
+        let mut item = monochrome(
+            NumericItem::new("Level", 8u8, 0, 10)
+                .with_step(2)
+                .with_value_converter(|v| v),
+        );
+
+        item.begin_edit();
+        item.edit(EditAction::Navigate(Navigation::Next));
+        assert_eq!(item.value_of(), 10);
+
+        item.edit(EditAction::Navigate(Navigation::Next));
+        assert_eq!(item.value_of(), 0);
+
+        item.edit(EditAction::Navigate(Navigation::Previous));
+        assert_eq!(item.value_of(), 10);
+
+        item.edit(EditAction::Navigate(Navigation::ForwardWrapping(8)));
+        assert_eq!(item.value_of(), 2);
+
+        item.edit(EditAction::Navigate(Navigation::BackwardWrapping(
+            usize::MAX,
+        )));
+        assert_eq!(item.value_of(), 8);
+
+        item.edit(EditAction::Navigate(Navigation::Forward(3)));
+        assert_eq!(item.value_of(), 10);
+    }
+
+    #[test]
+    fn reversed_bounds_are_swapped() {
+        let mut item =
+            monochrome(NumericItem::new("Level", 20u8, 10, 0).with_value_converter(|v| v));
+        assert_eq!(item.value_of(), 10);
+
+        item.begin_edit();
+        item.edit(EditAction::Navigate(Navigation::Beginning));
+        assert_eq!(item.value_of(), 0);
+    }
+
+    #[test]
+    fn editing_item_captures_navigation() {
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_menu_item(NumericItem::new("Volume", 5, 0, 10).with_value_converter(|v| v))
+            .add_item("Other", (), |_| 100)
+            .build();
+
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), None);
+        assert_eq!(
+            menu.interact(Interaction::Navigation(Navigation::Next)),
+            None
+        );
+        assert_eq!(menu.selected_value(), 6);
+
+        // Cancel editing
+        assert_eq!(menu.interact(Interaction::Action(Action::Back)), None);
+        assert_eq!(menu.selected_value(), 5);
+
+        // Navigation moves the selection again
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        assert_eq!(menu.selected_value(), 100);
+        menu.interact(Interaction::Navigation(Navigation::Previous));
+
+        menu.interact(Interaction::Action(Action::Select));
+        menu.interact(Interaction::Navigation(Navigation::Backward(2)));
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(3));
+        assert_eq!(menu.selected_value(), 3);
+    }
+
+    #[test]
+    fn single_touch_wraps_numeric_item() {
+        let mut menu = Menu::with_style(
+            "Root",
+            MenuStyle::new(BinaryColor::On).with_input_adapter(SingleTouch {
+                ignore_time: 1,
+                debounce_time: 1,
+                max_time: 5,
+                back_time: 0,
+            }),
+        )
+        .add_menu_item(NumericItem::new("Level", 9, 0, 10).with_value_converter(|v| v))
+        .build();
+        menu.interact(false);
+
+        // Hold to start editing, tapping only ever moves forward
+        assert_eq!(touch(|input| menu.interact(input), 5), None);
+        assert_eq!(touch(|input| menu.interact(input), 2), None);
+        assert_eq!(menu.selected_value(), 10);
+        assert_eq!(touch(|input| menu.interact(input), 2), None);
+        assert_eq!(menu.selected_value(), 0);
+
+        assert_eq!(touch(|input| menu.interact(input), 5), Some(0));
+    }
+}
@@ -0,0 +1,459 @@
+use core::ops::Range;
+
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point, Size},
+    primitives::Rectangle,
+    text::renderer::TextRenderer,
+};
+use embedded_layout::{prelude::*, view_group::ViewGroup};
+
+use crate::{
+    collection::MenuItemCollection,
+    items::{text_width, DrawContext, Marker, MenuLine, MenuListItem},
+    marquee::Marquee,
+    MenuTextStyle,
+};
+
+/// A single option of a [`RadioGroup`], displayed as its own row.
+pub struct RadioOption<T, V>
+where
+    T: AsRef<str>,
+{
+    title_text: T,
+    value: V,
+    active: bool,
+    markers: [&'static str; 2],
+    line: MenuLine,
+}
+
+impl<T, V> RadioOption<T, V>
+where
+    T: AsRef<str>,
+{
+    fn marker(&self) -> &'static str {
+        self.markers[self.active as usize]
+    }
+}
+
+impl<T, V> Marker for RadioOption<T, V> where T: AsRef<str> {}
+
+impl<T, V, C> MenuListItem<V, C> for RadioOption<T, V>
+where
+    T: AsRef<str>,
+    V: Clone,
+    C: PixelColor,
+{
+    fn value_of(&self) -> V {
+        self.value.clone()
+    }
+
+    fn interact(&mut self) -> V {
+        self.active = true;
+        self.value.clone()
+    }
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
+        let [inactive, active] = self.markers;
+        let longest = if text_width(active, text_style) > text_width(inactive, text_style) {
+            active
+        } else {
+            inactive
+        };
+
+        self.line = MenuLine::new(longest, text_style).with_lines(self.line.lines());
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_with_context(text_style, &DrawContext::default(), display)
+    }
+
+    fn draw_with_context<F, D>(
+        &self,
+        text_style: &F,
+        context: &DrawContext,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.line.draw_with_context(
+            self.title_text.as_ref(),
+            self.marker(),
+            text_style,
+            context,
+            display,
+        )
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.line.marquee_overflow(
+            self.title_text.as_ref(),
+            self.marker(),
+            text_style,
+            marquee,
+            width,
+        )
+    }
+}
+
+impl<T, V> View for RadioOption<T, V>
+where
+    T: AsRef<str>,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.line.translate_mut(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.line.bounds()
+    }
+}
+
+/// A group of options, each displayed as its own row, of which exactly one is active.
+///
+/// Selecting an option makes it the active one, and the menu returns the option's value converted
+/// using the group's value converter. Add the group to a menu using
+/// [`MenuBuilder::add_radio_group`].
+///
+/// [`MenuBuilder::add_radio_group`]: crate::builder::MenuBuilder::add_radio_group
+pub struct RadioGroup<T, V, R, const N: usize>
+where
+    T: AsRef<str>,
+{
+    options: [RadioOption<T, V>; N],
+    convert: fn(V) -> R,
+    key: Option<u32>,
+    details: Option<&'static str>,
+    /// The top left corner of the first option.
+    position: Point,
+}
+
+impl<T, V, const N: usize> RadioGroup<T, V, (), N>
+where
+    T: AsRef<str>,
+    V: Clone,
+{
+    const NOT_EMPTY: () = assert!(N > 0, "a RadioGroup needs at least one option");
+
+    /// Creates a group from `(title, value)` pairs. The option at index `active` is active, an
+    /// out of range index activates the last option.
+    ///
+    /// A group must have at least one option, empty groups are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # use embedded_menu::items::RadioGroup;
+    /// let options: [(&str, u8); 0] = [];
+    /// let group = RadioGroup::new(options, 0);
+    /// ```
+    pub fn new(options: [(T, V); N], active: usize) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::NOT_EMPTY;
+
+        let active = active.min(N - 1);
+        let mut idx = 0;
+        let options = options.map(|(title_text, value)| {
+            let option = RadioOption {
+                title_text,
+                value,
+                active: idx == active,
+                markers: ["( )", "(•)"],
+                line: MenuLine::empty(),
+            };
+            idx += 1;
+            option
+        });
+
+        Self {
+            options,
+            convert: |_| (),
+            key: None,
+            details: None,
+            position: Point::zero(),
+        }
+    }
+}
+
+impl<T, V, R, const N: usize> RadioGroup<T, V, R, N>
+where
+    T: AsRef<str>,
+    V: Clone,
+{
+    pub fn with_value_converter<R2>(self, convert: fn(V) -> R2) -> RadioGroup<T, V, R2, N> {
+        RadioGroup {
+            options: self.options,
+            convert,
+            key: self.key,
+            details: self.details,
+            position: self.position,
+        }
+    }
+
+    /// Sets the markers displayed next to the active and the inactive options. The defaults are
+    /// `(•)` and `( )`, fonts that don't contain the `•` glyph can display `(*)` instead.
+    pub fn with_markers(mut self, active: &'static str, inactive: &'static str) -> Self {
+        for option in self.options.iter_mut() {
+            option.markers = [inactive, active];
+        }
+        self
+    }
+
+    /// Sets the key of the first option, the other options are identified by the following keys.
+    /// See [`MenuListItem::key`].
+    pub fn with_key(self, key: u32) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets the description displayed after any of the options has been selected for a while.
+    /// See [`MenuListItem::details`].
+    pub fn with_detail_text(self, details: &'static str) -> Self {
+        Self {
+            details: Some(details),
+            ..self
+        }
+    }
+
+    /// Returns the index of the active option.
+    pub fn active(&self) -> usize {
+        self.options
+            .iter()
+            .position(|option| option.active)
+            .unwrap_or(0)
+    }
+
+    /// Returns the value of the active option.
+    pub fn value(&self) -> &V {
+        &self.options[self.active()].value
+    }
+
+    /// Sets the text style of the options and arranges them below each other.
+    pub(crate) fn set_style<C, F>(&mut self, text_style: &F)
+    where
+        C: PixelColor,
+        F: TextRenderer,
+    {
+        let mut top_left = self.position;
+        for option in self.options.iter_mut() {
+            MenuListItem::<V, C>::set_style(option, text_style);
+            option.translate_mut(top_left);
+            top_left.y += option.bounds().size.height as i32;
+        }
+    }
+}
+
+impl<T, V, R, C, const N: usize> MenuItemCollection<R, C> for RadioGroup<T, V, R, N>
+where
+    T: AsRef<str>,
+    V: Clone,
+    C: PixelColor,
+{
+    fn bounds_of(&self, nth: usize) -> Rectangle {
+        self.options[nth].bounds()
+    }
+
+    fn value_of(&self, nth: usize) -> R {
+        (self.convert)(MenuListItem::<V, C>::value_of(&self.options[nth]))
+    }
+
+    fn interact_with(&mut self, nth: usize) -> R {
+        for option in self.options.iter_mut() {
+            option.active = false;
+        }
+
+        (self.convert)(MenuListItem::<V, C>::interact(&mut self.options[nth]))
+    }
+
+    fn selectable(&self, _nth: usize) -> bool {
+        true
+    }
+
+    fn key_of(&self, nth: usize) -> Option<u32> {
+        self.key.map(|key| key.wrapping_add(nth as u32))
+    }
+
+    fn details_of(&self, _nth: usize) -> Option<&'static str> {
+        self.details
+    }
+
+    fn linked_items(&self, _nth: usize) -> Range<usize> {
+        // Activating an option deactivates the others.
+        0..N
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        nth: usize,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        MenuListItem::<V, C>::marquee_overflow(&self.options[nth], text_style, marquee, width)
+    }
+
+    fn count(&self) -> usize {
+        N
+    }
+
+    fn draw_styled<F, D>(
+        &self,
+        text_style: &F,
+        context: &DrawContext,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        for (nth, option) in self.options.iter().enumerate() {
+            MenuListItem::<V, C>::draw_with_context(
+                option,
+                text_style,
+                &context.nth(nth),
+                display,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, V, R, const N: usize> View for RadioGroup<T, V, R, N>
+where
+    T: AsRef<str>,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        for option in self.options.iter_mut() {
+            option.translate_impl(by);
+        }
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let mut size = Size::zero();
+
+        for option in self.options.iter() {
+            let option_size = option.bounds().size;
+            size = Size::new(
+                size.width.max(option_size.width),
+                size.height + option_size.height,
+            );
+        }
+
+        Rectangle::new(self.position, size)
+    }
+}
+
+impl<T, V, R, const N: usize> ViewGroup for RadioGroup<T, V, R, N>
+where
+    T: AsRef<str>,
+{
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn at(&self, idx: usize) -> &dyn View {
+        &self.options[idx]
+    }
+
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        &mut self.options[idx]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        test_utils::{assert_redrawn, mock_display},
+        Menu,
+    };
+
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    use super::*;
+
+    #[test]
+    fn exactly_one_option_is_active() {
+        let mut group = RadioGroup::new([("Low", 1), ("Medium", 2), ("High", 3)], 1)
+            .with_value_converter(|v| v * 10)
+            .with_key(10)
+            .with_detail_text("Fan speed");
+
+        assert_eq!(group.active(), 1);
+        assert_eq!(
+            MenuItemCollection::<_, BinaryColor>::interact_with(&mut group, 2),
+            30
+        );
+        assert_eq!(group.active(), 2);
+        assert_eq!(*group.value(), 3);
+        assert_eq!(
+            group.options.iter().filter(|option| option.active).count(),
+            1
+        );
+        assert_eq!(group.options[2].marker(), "(•)");
+        assert_eq!(group.options[1].marker(), "( )");
+        assert_eq!(
+            MenuItemCollection::<_, BinaryColor>::details_of(&group, 0),
+            Some("Fan speed")
+        );
+        assert_eq!(
+            MenuItemCollection::<_, BinaryColor>::key_of(&group, 2),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn out_of_range_active_option_is_clamped() {
+        let group = RadioGroup::new([("Low", 1), ("Medium", 2), ("High", 3)], 7);
+
+        assert_eq!(group.active(), 2);
+        assert_eq!(*group.value(), 3);
+        assert_eq!(
+            group.options.iter().filter(|option| option.active).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn radio_group_redraws_previously_active_option() {
+        use crate::items::RadioGroup;
+
+        let mut menu = Menu::<_, _, _, _, _, _, BinaryColor, _, _, _>::build("Root")
+            .add_section_title("Speed")
+            .add_radio_group(
+                RadioGroup::new([("Slow", 1), ("Normal", 2), ("Fast", 3)], 0)
+                    .with_value_converter(|speed| speed * 10),
+            )
+            .add_item("Back", (), |_| 0)
+            .build();
+
+        let mut display = mock_display();
+        menu.draw_partial(&mut display).unwrap();
+
+        assert_eq!(menu.selected_value(), 10);
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.draw_partial(&mut display).unwrap();
+
+        assert_eq!(menu.interact(Interaction::Action(Action::Select)), Some(30));
+        assert!(matches!(menu.changed_items, crate::ChangedItems::Many));
+
+        // The whole list is redrawn, including the row of the previously active option.
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let list_area = menu.last_drawn.unwrap().list_area;
+        assert_redrawn(&regions, list_area);
+    }
+}
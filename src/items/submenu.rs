@@ -0,0 +1,248 @@
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point},
+    primitives::Rectangle,
+    text::renderer::TextRenderer,
+};
+use embedded_layout::{view_group::ViewGroup, View};
+
+use crate::{
+    collection::{LevelVisitor, LevelVisitorMut, MenuItemCollection},
+    items::{DrawContext, Marker, MenuLine, MenuListItem},
+    marquee::Marquee,
+    MenuTextStyle,
+};
+
+/// A menu item that opens a list of child items when selected.
+///
+/// The menu keeps track of the opened submenus. Use [`Action::Back`] to return to the parent
+/// menu.
+///
+/// [`Action::Back`]: crate::interaction::Action::Back
+pub struct SubMenu<T, VG, R>
+where
+    T: AsRef<str>,
+    VG: ViewGroup,
+{
+    title_text: T,
+    items: VG,
+    value: R,
+    key: Option<u32>,
+    details: Option<&'static str>,
+    line: MenuLine,
+}
+
+impl<T, VG, R> SubMenu<T, VG, R>
+where
+    T: AsRef<str>,
+    VG: ViewGroup,
+{
+    /// Creates a new submenu item.
+    ///
+    /// The `items` are expected to be arranged already. `value` is returned when the menu is
+    /// asked for the selected value while this item is selected.
+    pub fn new(title_text: T, items: VG, value: R) -> Self {
+        Self {
+            title_text,
+            items,
+            value,
+            key: None,
+            details: None,
+            line: MenuLine::empty(),
+        }
+    }
+
+    /// Sets the key that identifies the item. See [`MenuListItem::key`].
+    pub fn with_key(self, key: u32) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets the description displayed after the item has been selected for a while. See
+    /// [`MenuListItem::details`].
+    pub fn with_detail_text(self, details: &'static str) -> Self {
+        Self {
+            details: Some(details),
+            ..self
+        }
+    }
+
+    /// Reserves `lines` lines of text for the item. A title that does not fit on a single line is
+    /// word-wrapped.
+    pub fn with_lines(self, lines: u32) -> Self {
+        Self {
+            line: self.line.with_lines(lines),
+            ..self
+        }
+    }
+}
+
+impl<T, VG, R> Marker for SubMenu<T, VG, R>
+where
+    T: AsRef<str>,
+    VG: ViewGroup,
+{
+}
+
+impl<T, VG, R, C> MenuListItem<R, C> for SubMenu<T, VG, R>
+where
+    T: AsRef<str>,
+    VG: ViewGroup + MenuItemCollection<R, C>,
+    R: Clone,
+    C: PixelColor,
+{
+    fn value_of(&self) -> R {
+        self.value.clone()
+    }
+
+    fn interact(&mut self) -> R {
+        self.value.clone()
+    }
+
+    fn key(&self) -> Option<u32> {
+        self.key
+    }
+
+    fn details(&self) -> Option<&'static str> {
+        self.details
+    }
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
+        self.line = MenuLine::new(">", text_style).with_lines(self.line.lines());
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_with_context(text_style, &DrawContext::default(), display)
+    }
+
+    fn draw_with_context<F, D>(
+        &self,
+        text_style: &F,
+        context: &DrawContext,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        self.line
+            .draw_with_context(self.title_text.as_ref(), ">", text_style, context, display)
+    }
+
+    fn marquee_overflow<F: TextRenderer>(
+        &self,
+        text_style: &F,
+        marquee: &Marquee,
+        width: u32,
+    ) -> u32 {
+        self.line
+            .marquee_overflow(self.title_text.as_ref(), ">", text_style, marquee, width)
+    }
+
+    fn visit_submenu<V>(&self, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitor<R, C>,
+    {
+        match path.split_first() {
+            None => Some(visitor.visit(self.title_text.as_ref(), &self.items)),
+            Some((&nth, path)) => self.items.visit_submenu(nth, path, visitor),
+        }
+    }
+
+    fn visit_submenu_mut<V>(&mut self, path: &[usize], visitor: V) -> Option<V::Output>
+    where
+        V: LevelVisitorMut<R, C>,
+    {
+        match path.split_first() {
+            None => Some(visitor.visit(self.title_text.as_ref(), &mut self.items)),
+            Some((&nth, path)) => self.items.visit_submenu_mut(nth, path, visitor),
+        }
+    }
+}
+
+impl<T, VG, R> View for SubMenu<T, VG, R>
+where
+    T: AsRef<str>,
+    VG: ViewGroup,
+{
+    fn translate_impl(&mut self, by: Point) {
+        // The child items are laid out independently of the parent menu.
+        self.line.translate_mut(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.line.bounds()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        test_utils::mock_display,
+        Menu,
+    };
+    use embedded_graphics::{pixelcolor::BinaryColor, Drawable};
+
+    #[test]
+    fn submenu_navigation_restores_parent_selection() {
+        let mut menu = Menu::build("Root")
+            .add_item("Item 0", (), |_| 0)
+            .add_item("Item 1", (), |_| 1)
+            .add_submenu(
+                Menu::build("Settings")
+                    .add_item("Item 3", (), |_| 3)
+                    .add_submenu(Menu::build("Display").add_item("Item 5", (), |_| 5), 4),
+                2,
+            )
+            .build();
+
+        let interact = |menu: &mut Menu<_, _, _, _, _, _, BinaryColor, _, _, _>, interaction| {
+            let value = menu.interact(interaction);
+
+            let mut display = mock_display();
+            menu.update(&display);
+            menu.draw(&mut display).unwrap();
+
+            value
+        };
+
+        interact(&mut menu, Interaction::Navigation(Navigation::JumpTo(2)));
+        assert_eq!(menu.selected_value(), 2);
+
+        assert_eq!(
+            interact(&mut menu, Interaction::Action(Action::Select)),
+            None
+        );
+        assert_eq!(menu.state().depth(), 1);
+        assert_eq!(menu.selected_value(), 3);
+
+        interact(&mut menu, Interaction::Navigation(Navigation::Next));
+        assert_eq!(
+            interact(&mut menu, Interaction::Action(Action::Select)),
+            None
+        );
+        assert_eq!(menu.state().depth(), 2);
+        assert_eq!(
+            interact(&mut menu, Interaction::Action(Action::Select)),
+            Some(5)
+        );
+
+        assert_eq!(interact(&mut menu, Interaction::Action(Action::Back)), None);
+        assert_eq!(menu.selected_value(), 4);
+
+        assert_eq!(interact(&mut menu, Interaction::Action(Action::Back)), None);
+        assert_eq!(menu.state().depth(), 0);
+        assert_eq!(menu.selected_value(), 2);
+
+        // Going back from the root menu does nothing
+        assert_eq!(interact(&mut menu, Interaction::Action(Action::Back)), None);
+        assert_eq!(menu.selected_value(), 2);
+    }
+}
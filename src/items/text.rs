@@ -0,0 +1,566 @@
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point, Size},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+use embedded_layout::View;
+use embedded_text::TextBox;
+
+use crate::{
+    buffer::TextBuffer,
+    interaction::Navigation,
+    items::{text_width, EditAction, Marker, MenuLine, MenuListItem},
+    MenuTextStyle,
+};
+
+/// Letters, digits and space.
+pub const ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+
+/// Decimal digits, e.g. for PIN codes.
+pub const DIGITS: &str = "0123456789";
+
+const APPEND_MARKER: &str = "_";
+const CONFIRM_MARKER: &str = "OK";
+const INSERT_MARKER: &str = "INS";
+const DELETE_MARKER: &str = "DEL";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Editing {
+    /// Moving the cursor. The positions after the last character append a new character and
+    /// confirm the edit.
+    Cursor(usize),
+    /// Choosing the character at `position`. `previous` is the character being replaced, or
+    /// `None` if a new character is inserted.
+    Picking {
+        position: usize,
+        choice: usize,
+        previous: Option<char>,
+    },
+}
+
+/// An entry of the character picker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Choice {
+    Char(char),
+    Insert,
+    Delete,
+}
+
+/// A menu item that edits a fixed capacity text, e.g. a device name or a PIN code.
+///
+/// Selecting the item enters editing mode. In editing mode, navigation moves the cursor over the
+/// characters of the text, followed by a slot that appends a new character and a slot that
+/// confirms the edit. Selecting a character opens a picker that cycles through the character set
+/// and offers inserting a new character before the selected one, or deleting it. Selecting a
+/// choice applies it, going back closes the picker without changes. Going back while moving the
+/// cursor restores the original text.
+///
+/// Only the navigation and select interactions are necessary to edit the text, so every input
+/// adapter can be used.
+pub struct TextItem<T, R, const N: usize>
+where
+    T: AsRef<str>,
+{
+    title_text: T,
+    convert: fn(TextBuffer<N>) -> R,
+    value: TextBuffer<N>,
+    original: TextBuffer<N>,
+    charset: &'static str,
+    masked: bool,
+    editing: Option<Editing>,
+    key: Option<u32>,
+    hint: Option<&'static str>,
+    details: Option<&'static str>,
+    line: MenuLine,
+}
+
+impl<T, const N: usize> TextItem<T, (), N>
+where
+    T: AsRef<str>,
+{
+    /// Creates a new text item. Characters of `value` that don't fit `N` bytes are dropped.
+    pub fn new(title_text: T, value: &str) -> Self {
+        let value = TextBuffer::from_str_truncated(value);
+
+        Self {
+            title_text,
+            convert: |_| (),
+            value,
+            original: value,
+            charset: ALPHANUMERIC,
+            masked: false,
+            editing: None,
+            key: None,
+            hint: None,
+            details: None,
+            line: MenuLine::empty(),
+        }
+    }
+}
+
+impl<T, R, const N: usize> TextItem<T, R, N>
+where
+    T: AsRef<str>,
+{
+    pub fn with_value_converter<R2>(self, convert: fn(TextBuffer<N>) -> R2) -> TextItem<T, R2, N> {
+        TextItem {
+            convert,
+            title_text: self.title_text,
+            value: self.value,
+            original: self.original,
+            charset: self.charset,
+            masked: self.masked,
+            editing: self.editing,
+            key: self.key,
+            hint: self.hint,
+            details: self.details,
+            line: self.line,
+        }
+    }
+
+    /// Sets the characters the picker cycles through. See [`ALPHANUMERIC`] and [`DIGITS`].
+    pub fn with_charset(self, charset: &'static str) -> Self {
+        Self { charset, ..self }
+    }
+
+    /// Displays the characters as `*`, except for the one being picked.
+    pub fn masked(self) -> Self {
+        Self {
+            masked: true,
+            ..self
+        }
+    }
+
+    /// Sets the key that identifies the item. See [`MenuListItem::key`].
+    pub fn with_key(self, key: u32) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets the hint displayed in the footer while the item is selected. See
+    /// [`MenuListItem::hint`].
+    pub fn with_hint(self, hint: &'static str) -> Self {
+        Self {
+            hint: Some(hint),
+            ..self
+        }
+    }
+
+    /// Sets the description displayed after the item has been selected for a while. See
+    /// [`MenuListItem::details`].
+    pub fn with_detail_text(self, details: &'static str) -> Self {
+        Self {
+            details: Some(details),
+            ..self
+        }
+    }
+
+    /// Returns the current text.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    fn choice_count(&self, replacing: bool) -> usize {
+        let extra = if replacing { 2 } else { 0 };
+        self.charset.chars().count() + extra
+    }
+
+    fn choice(&self, choice: usize) -> Choice {
+        match self.charset.chars().nth(choice) {
+            Some(c) => Choice::Char(c),
+            None if choice == self.charset.chars().count() => Choice::Insert,
+            None => Choice::Delete,
+        }
+    }
+
+    fn move_cursor(&mut self, position: usize, navigation: Navigation) {
+        // The characters, the append slot and the confirm slot
+        let count = self.value.char_count() + 2;
+        let position = navigation.calculate_selection(position, count, |_| true);
+
+        self.editing = Some(Editing::Cursor(position));
+    }
+
+    fn pick(&mut self, position: usize, choice: usize, previous: Option<char>, nav: Navigation) {
+        let count = self.choice_count(previous.is_some());
+        let choice = nav.calculate_selection(choice, count, |_| true);
+
+        self.editing = Some(Editing::Picking {
+            position,
+            choice,
+            previous,
+        });
+    }
+
+    fn select_cursor(&mut self, position: usize) -> Option<R> {
+        let len = self.value.char_count();
+
+        if position == len + 1 {
+            self.editing = None;
+            return Some((self.convert)(self.value));
+        }
+
+        let previous = self.value.as_str().chars().nth(position);
+        let choice = previous
+            .and_then(|previous| self.charset.chars().position(|c| c == previous))
+            .unwrap_or(0);
+
+        self.editing = Some(Editing::Picking {
+            position,
+            choice,
+            previous,
+        });
+
+        None
+    }
+
+    fn select_choice(&mut self, position: usize, choice: usize, previous: Option<char>) {
+        let position = match (self.choice(choice), previous) {
+            (Choice::Char(c), None) if self.value.insert(position, c) => position + 1,
+            (Choice::Char(c), Some(_)) if self.value.replace(position, c) => position + 1,
+            (Choice::Char(_), _) => position,
+            (Choice::Insert, _) => {
+                self.editing = Some(Editing::Picking {
+                    position,
+                    choice: 0,
+                    previous: None,
+                });
+                return;
+            }
+            (Choice::Delete, _) => {
+                self.value.remove(position);
+                position
+            }
+        };
+
+        self.editing = Some(Editing::Cursor(position));
+    }
+
+    /// Returns the parts of the displayed text: the text before the cursor, the cursor and the
+    /// text after the cursor.
+    fn parts<'a>(&'a self, char_buffer: &'a mut [u8; 4]) -> (&'a str, &'a str, &'a str) {
+        let text = self.value.as_str();
+        let split = |position: usize, skip: usize| {
+            let mut offsets = text.char_indices().map(|(offset, _)| offset);
+            let start = offsets.nth(position).unwrap_or(text.len());
+            let end = match skip {
+                0 => start,
+                _ => offsets.next().unwrap_or(text.len()),
+            };
+
+            (&text[..start], &text[end..])
+        };
+
+        match self.editing {
+            None => (text, "", ""),
+            Some(Editing::Cursor(position)) => {
+                let (before, after) = split(position, 1);
+                let len = self.value.char_count();
+                let cursor = if position == len {
+                    APPEND_MARKER
+                } else if position > len {
+                    CONFIRM_MARKER
+                } else {
+                    &text[before.len()..text.len() - after.len()]
+                };
+
+                (before, cursor, after)
+            }
+            Some(Editing::Picking {
+                position,
+                choice,
+                previous,
+            }) => {
+                let (before, after) = split(position, previous.is_some() as usize);
+                let cursor = match self.choice(choice) {
+                    Choice::Char(c) => &*c.encode_utf8(char_buffer),
+                    Choice::Insert => INSERT_MARKER,
+                    Choice::Delete => DELETE_MARKER,
+                };
+
+                (before, cursor, after)
+            }
+        }
+    }
+}
+
+/// Returns `count` asterisks.
+fn mask<const N: usize>(count: usize) -> TextBuffer<N> {
+    let mut masked = TextBuffer::new();
+    for _ in 0..count {
+        masked.insert(0, '*');
+    }
+    masked
+}
+
+impl<T, R, const N: usize> Marker for TextItem<T, R, N> where T: AsRef<str> {}
+
+impl<T, R, C, const N: usize> MenuListItem<R, C> for TextItem<T, R, N>
+where
+    T: AsRef<str>,
+    C: PixelColor,
+{
+    fn value_of(&self) -> R {
+        (self.convert)(self.value)
+    }
+
+    fn interact(&mut self) -> R {
+        (self.convert)(self.value)
+    }
+
+    fn key(&self) -> Option<u32> {
+        self.key
+    }
+
+    fn hint(&self) -> Option<&'static str> {
+        self.hint
+    }
+
+    fn details(&self) -> Option<&'static str> {
+        self.details
+    }
+
+    fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    fn begin_edit(&mut self) -> bool {
+        self.original = self.value;
+        self.editing = Some(Editing::Cursor(self.value.char_count()));
+        true
+    }
+
+    fn edit(&mut self, action: EditAction) -> Option<R> {
+        let editing = self.editing?;
+
+        match (editing, action) {
+            (Editing::Cursor(position), EditAction::Navigate(navigation)) => {
+                self.move_cursor(position, navigation);
+                None
+            }
+            (Editing::Cursor(position), EditAction::Select) => self.select_cursor(position),
+            (Editing::Cursor(_), EditAction::Cancel) => {
+                self.value = self.original;
+                self.editing = None;
+                None
+            }
+            (
+                Editing::Picking {
+                    position,
+                    choice,
+                    previous,
+                },
+                EditAction::Navigate(navigation),
+            ) => {
+                self.pick(position, choice, previous, navigation);
+                None
+            }
+            (
+                Editing::Picking {
+                    position,
+                    choice,
+                    previous,
+                },
+                EditAction::Select,
+            ) => {
+                self.select_choice(position, choice, previous);
+                None
+            }
+            (Editing::Picking { position, .. }, EditAction::Cancel) => {
+                self.editing = Some(Editing::Cursor(position));
+                None
+            }
+        }
+    }
+
+    fn set_style<F: TextRenderer>(&mut self, text_style: &F) {
+        // The width of the value changes while editing, it is measured when drawing.
+        self.line = MenuLine::new("", text_style);
+    }
+
+    fn draw_styled<F, D>(&self, text_style: &F, display: &mut D) -> Result<(), D::Error>
+    where
+        F: MenuTextStyle<C>,
+        D: DrawTarget<Color = C>,
+    {
+        let display_area = display.bounding_box();
+        let bounds = self.line.bounds();
+
+        if bounds.intersection(&display_area).size.height == 0 {
+            return Ok(());
+        }
+
+        let mut char_buffer = [0; 4];
+        let (before, cursor, after) = self.parts(&mut char_buffer);
+
+        let (masked_before, masked_after) = if self.masked {
+            (
+                mask::<N>(before.chars().count()),
+                mask::<N>(after.chars().count()),
+            )
+        } else {
+            (TextBuffer::new(), TextBuffer::new())
+        };
+        let (before, after) = if self.masked {
+            (masked_before.as_str(), masked_after.as_str())
+        } else {
+            (before, after)
+        };
+        let cursor = match self.editing {
+            Some(Editing::Cursor(position))
+                if self.masked && position < self.value.char_count() =>
+            {
+                "*"
+            }
+            _ => cursor,
+        };
+        let (open, close) = match self.editing {
+            None => ("", ""),
+            Some(Editing::Cursor(_)) => ("[", "]"),
+            Some(Editing::Picking { .. }) => ("<", ">"),
+        };
+
+        let parts = [before, open, cursor, close, after];
+        let value_width = parts
+            .iter()
+            .map(|part| text_width(part, text_style))
+            .sum::<u32>();
+
+        let title_bounds = Rectangle::new(
+            bounds.top_left,
+            Size::new(
+                display_area.size.width.saturating_sub(value_width),
+                bounds.size.height + 1,
+            ),
+        );
+        TextBox::new(self.title_text.as_ref(), title_bounds, *text_style).draw(display)?;
+
+        // Right-align the value, but keep the cursor visible if the value is too long.
+        let right = bounds.top_left.x + display_area.size.width as i32;
+        let cursor_start = text_width(before, text_style) as i32;
+        let x = (right - value_width as i32).max(bounds.top_left.x - cursor_start);
+
+        let mut position = Point::new(x, bounds.top_left.y);
+        for part in parts {
+            position =
+                Text::with_baseline(part, position, *text_style, Baseline::Top).draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, R, const N: usize> View for TextItem<T, R, N>
+where
+    T: AsRef<str>,
+{
+    fn translate_impl(&mut self, by: Point) {
+        self.line.translate_mut(by);
+    }
+
+    fn bounds(&self) -> Rectangle {
+        self.line.bounds()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    use super::*;
+
+    type Item<const N: usize> = TextItem<&'static str, TextBuffer<N>, N>;
+
+    fn edit<const N: usize>(item: &mut Item<N>, action: EditAction) -> Option<TextBuffer<N>> {
+        MenuListItem::<_, BinaryColor>::edit(item, action)
+    }
+
+    fn navigate<const N: usize>(item: &mut Item<N>, navigation: Navigation) {
+        assert_eq!(edit(item, EditAction::Navigate(navigation)), None);
+    }
+
+    fn displayed<const N: usize>(item: &Item<N>) -> String {
+        let mut char_buffer = [0; 4];
+        let (before, cursor, after) = item.parts(&mut char_buffer);
+        format!("{before}[{cursor}]{after}")
+    }
+
+    #[test]
+    fn edits_text_with_navigation_and_select() {
+        let mut item = TextItem::<_, _, 8>::new("Name", "ab")
+            .with_charset("abc")
+            .with_value_converter(|v| v);
+
+        assert!(MenuListItem::<_, BinaryColor>::begin_edit(&mut item));
+        assert_eq!(displayed(&item), "ab[_]");
+
+        // Append a character: open the picker, choose 'c'
+        assert_eq!(edit(&mut item, EditAction::Select), None);
+        navigate(&mut item, Navigation::Previous);
+        assert_eq!(displayed(&item), "ab[c]");
+        assert_eq!(edit(&mut item, EditAction::Select), None);
+        assert_eq!(item.value(), "abc");
+
+        // Replace the first character
+        navigate(&mut item, Navigation::Beginning);
+        edit(&mut item, EditAction::Select);
+        navigate(&mut item, Navigation::Next);
+        edit(&mut item, EditAction::Select);
+        assert_eq!(item.value(), "bbc");
+
+        // Delete the second character, which is now selected
+        edit(&mut item, EditAction::Select);
+        navigate(&mut item, Navigation::End);
+        assert_eq!(displayed(&item), "b[DEL]c");
+        edit(&mut item, EditAction::Select);
+        assert_eq!(item.value(), "bc");
+
+        // Insert before the first character
+        navigate(&mut item, Navigation::Beginning);
+        edit(&mut item, EditAction::Select);
+        navigate(&mut item, Navigation::JumpTo(3));
+        assert_eq!(displayed(&item), "[INS]c");
+        edit(&mut item, EditAction::Select);
+        assert_eq!(displayed(&item), "[a]bc");
+        edit(&mut item, EditAction::Select);
+        assert_eq!(item.value(), "abc");
+
+        // Confirm
+        navigate(&mut item, Navigation::End);
+        assert_eq!(displayed(&item), "abc[OK]");
+        let value = edit(&mut item, EditAction::Select).unwrap();
+        assert_eq!(value.as_str(), "abc");
+        assert!(!MenuListItem::<_, BinaryColor>::is_editing(&item));
+    }
+
+    #[test]
+    fn cancel_restores_text() {
+        let mut item = TextItem::<_, _, 4>::new("PIN", "12")
+            .with_charset(DIGITS)
+            .masked()
+            .with_value_converter(|v| v);
+
+        MenuListItem::<_, BinaryColor>::begin_edit(&mut item);
+        navigate(&mut item, Navigation::Beginning);
+        edit(&mut item, EditAction::Select);
+        navigate(&mut item, Navigation::Next);
+
+        // Closing the picker keeps the original character
+        edit(&mut item, EditAction::Cancel);
+        assert!(MenuListItem::<_, BinaryColor>::is_editing(&item));
+        assert_eq!(item.value(), "12");
+
+        edit(&mut item, EditAction::Select);
+        navigate(&mut item, Navigation::End);
+        edit(&mut item, EditAction::Select);
+        assert_eq!(item.value(), "2");
+
+        edit(&mut item, EditAction::Cancel);
+        assert!(!MenuListItem::<_, BinaryColor>::is_editing(&item));
+        assert_eq!(item.value(), "12");
+    }
+}
@@ -1,46 +1,78 @@
 #![cfg_attr(not(test), no_std)]
 
 pub mod adapters;
+pub mod buffer;
 pub mod builder;
 pub mod collection;
+pub mod dialog;
+pub mod dirty_regions;
+pub mod header;
 pub mod interaction;
 pub mod items;
+pub mod marquee;
+pub mod scroll;
+pub mod scrollbar;
 pub mod selection_indicator;
 pub mod theme;
 
 mod margin;
+mod navigation;
+#[cfg(feature = "async")]
+mod runner;
+#[cfg(test)]
+mod test_utils;
 
 use crate::{
+    adapters::clip::ClippingDrawTargetExt,
+    buffer::TextBuffer,
     builder::MenuBuilder,
-    collection::MenuItemCollection,
+    collection::{LevelVisitor, LevelVisitorMut, MenuItemCollection},
+    dialog::{ConfirmDialog, MessageBox, CONFIRM},
+    dirty_regions::DirtyRegions,
+    header::HeaderStyle,
     interaction::{
         programmed::Programmed, Action, InputAdapter, InputAdapterSource, InputResult, InputState,
         Interaction, Navigation,
     },
+    items::{text_width, EditAction},
+    margin::Insets,
+    marquee::{Marquee, TitleOverflow},
+    navigation::NavigationStack,
+    scroll::ListScrolling,
+    scrollbar::{Bar, ScrollPosition, ScrollbarStyle},
     selection_indicator::{
         style::{line::Line as LineIndicator, IndicatorStyle},
-        AnimatedPosition, Indicator, SelectionIndicatorController, State as IndicatorState,
-        StaticPosition,
+        AnimatedPosition, Easing, Indicator, SelectionIndicatorController, State as IndicatorState,
+        StaticPosition, FRAME_TIME_MS,
     },
     theme::Theme,
 };
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::Range};
 use embedded_graphics::{
     draw_target::DrawTarget,
     geometry::{AnchorPoint, AnchorX, AnchorY},
-    mono_font::{ascii::FONT_6X10, MonoFont, MonoTextStyle},
-    pixelcolor::BinaryColor,
-    prelude::{Dimensions, DrawTargetExt, Point},
-    primitives::{Line, Primitive, PrimitiveStyle, Rectangle},
+    mono_font::{ascii::FONT_6X10, MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::{BinaryColor, PixelColor},
+    prelude::{Dimensions, DrawTargetExt, Point, Size},
+    primitives::{Line, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
+    text::{
+        renderer::{CharacterStyle, TextRenderer},
+        Baseline, Text,
+    },
     Drawable,
 };
-use embedded_layout::{layout::linear::LinearLayout, prelude::*, view_group::ViewGroup};
+use embedded_layout::{prelude::*, view_group::ViewGroup};
 use embedded_text::{
-    style::{HeightMode, TextBoxStyle},
+    style::{HeightMode, TextBoxStyleBuilder},
     TextBox,
 };
 
-pub use embedded_menu_macros::SelectValue;
+pub use embedded_menu_macros::{Menu, SelectValue};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use embedded_layout::object_chain::{Chain, Link};
+}
 
 #[derive(Copy, Clone, Debug)]
 pub enum DisplayScrollbar {
@@ -49,24 +81,62 @@ pub enum DisplayScrollbar {
     Auto,
 }
 
+/// Text styles that can be used to render the menu title and items.
+///
+/// This trait is implemented for every suitable [`TextRenderer`], which means fonts with
+/// proportional glyph widths can be used in addition to [`MonoTextStyle`]. The menu overrides
+/// the text color of the style when drawing.
+pub trait MenuTextStyle<C>: TextRenderer<Color = C> + CharacterStyle<Color = C> + Copy {}
+
+impl<S, C> MenuTextStyle<C> for S where S: TextRenderer<Color = C> + CharacterStyle<Color = C> + Copy
+{}
+
 #[derive(Copy, Clone, Debug)]
-pub struct MenuStyle<S, IT, P, R, T> {
+pub struct MenuStyle<S, IT, P, R, T, F, TF, SB> {
     pub(crate) theme: T,
     pub(crate) scrollbar: DisplayScrollbar,
-    pub(crate) font: &'static MonoFont<'static>,
-    pub(crate) title_font: &'static MonoFont<'static>,
+    pub(crate) scrolling: ListScrolling,
+    pub(crate) header: HeaderStyle,
+    pub(crate) footer: bool,
+    pub(crate) title_overflow: TitleOverflow,
+    pub(crate) marquee: Option<Marquee>,
+    pub(crate) details_delay: Option<u32>,
+    pub(crate) font: F,
+    pub(crate) title_font: TF,
+    pub(crate) scrollbar_style: SB,
     pub(crate) input_adapter: IT,
     pub(crate) indicator: Indicator<P, S>,
     _marker: PhantomData<R>,
 }
 
-impl<R> Default for MenuStyle<LineIndicator, Programmed, StaticPosition, R, BinaryColor> {
+impl<R> Default
+    for MenuStyle<
+        LineIndicator,
+        Programmed,
+        StaticPosition,
+        R,
+        BinaryColor,
+        MonoTextStyle<'static, BinaryColor>,
+        MonoTextStyle<'static, BinaryColor>,
+        Bar,
+    >
+{
     fn default() -> Self {
         Self::new(BinaryColor::On)
     }
 }
 
-impl<T, R> MenuStyle<LineIndicator, Programmed, StaticPosition, R, T>
+impl<T, R>
+    MenuStyle<
+        LineIndicator,
+        Programmed,
+        StaticPosition,
+        R,
+        T,
+        MonoTextStyle<'static, T::Color>,
+        MonoTextStyle<'static, T::Color>,
+        Bar,
+    >
 where
     T: Theme,
 {
@@ -74,8 +144,15 @@ where
         Self {
             theme,
             scrollbar: DisplayScrollbar::Auto,
-            font: &FONT_6X10,
-            title_font: &FONT_6X10,
+            scrolling: ListScrolling::Immediate,
+            header: HeaderStyle::new(),
+            footer: false,
+            title_overflow: TitleOverflow::Clip,
+            marquee: None,
+            details_delay: None,
+            font: MonoTextStyleBuilder::new().font(&FONT_6X10).build(),
+            title_font: MonoTextStyleBuilder::new().font(&FONT_6X10).build(),
+            scrollbar_style: Bar::new(),
             input_adapter: Programmed,
             indicator: Indicator {
                 style: LineIndicator,
@@ -86,37 +163,198 @@ where
     }
 }
 
-impl<S, IT, P, R, T> MenuStyle<S, IT, P, R, T>
+impl<S, IT, P, R, T, F, TF, SB> MenuStyle<S, IT, P, R, T, F, TF, SB>
 where
     S: IndicatorStyle,
     IT: InputAdapterSource<R>,
     P: SelectionIndicatorController,
     T: Theme,
+    F: MenuTextStyle<T::Color>,
+    TF: MenuTextStyle<T::Color>,
+    SB: ScrollbarStyle<T::Color>,
 {
-    pub const fn with_font(self, font: &'static MonoFont<'static>) -> Self {
-        Self { font, ..self }
+    pub const fn with_font(
+        self,
+        font: &'static MonoFont<'static>,
+    ) -> MenuStyle<S, IT, P, R, T, MonoTextStyle<'static, T::Color>, TF, SB> {
+        self.with_text_style(MonoTextStyleBuilder::new().font(font).build())
+    }
+
+    pub const fn with_title_font(
+        self,
+        title_font: &'static MonoFont<'static>,
+    ) -> MenuStyle<S, IT, P, R, T, F, MonoTextStyle<'static, T::Color>, SB> {
+        self.with_title_text_style(MonoTextStyleBuilder::new().font(title_font).build())
+    }
+
+    /// Sets the text style used to draw the menu items.
+    ///
+    /// Use this method to draw menu items using any [`TextRenderer`] implementation.
+    pub const fn with_text_style<F2>(self, font: F2) -> MenuStyle<S, IT, P, R, T, F2, TF, SB>
+    where
+        F2: MenuTextStyle<T::Color>,
+    {
+        MenuStyle {
+            theme: self.theme,
+            scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
+            font,
+            title_font: self.title_font,
+            scrollbar_style: self.scrollbar_style,
+            input_adapter: self.input_adapter,
+            indicator: self.indicator,
+            _marker: PhantomData,
+        }
     }
 
-    pub const fn with_title_font(self, title_font: &'static MonoFont<'static>) -> Self {
-        Self { title_font, ..self }
+    /// Sets the text style used to draw the menu title.
+    ///
+    /// Use this method to draw the title using any [`TextRenderer`] implementation.
+    pub const fn with_title_text_style<TF2>(
+        self,
+        title_font: TF2,
+    ) -> MenuStyle<S, IT, P, R, T, F, TF2, SB>
+    where
+        TF2: MenuTextStyle<T::Color>,
+    {
+        MenuStyle {
+            theme: self.theme,
+            scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
+            font: self.font,
+            title_font,
+            scrollbar_style: self.scrollbar_style,
+            input_adapter: self.input_adapter,
+            indicator: self.indicator,
+            _marker: PhantomData,
+        }
     }
 
+    /// Sets when the scrollbar is displayed.
     pub const fn with_scrollbar_style(self, scrollbar: DisplayScrollbar) -> Self {
         Self { scrollbar, ..self }
     }
 
+    /// Sets what the scrollbar looks like. See the [`scrollbar`] module for the available styles.
+    ///
+    /// The width of the menu list is reduced by the width of the scrollbar.
+    pub const fn with_scrollbar<SB2>(
+        self,
+        scrollbar_style: SB2,
+    ) -> MenuStyle<S, IT, P, R, T, F, TF, SB2>
+    where
+        SB2: ScrollbarStyle<T::Color>,
+    {
+        MenuStyle {
+            theme: self.theme,
+            scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
+            font: self.font,
+            title_font: self.title_font,
+            scrollbar_style,
+            input_adapter: self.input_adapter,
+            indicator: self.indicator,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets how the header, which displays the title and the status, is drawn.
+    pub const fn with_header(self, header: HeaderStyle) -> Self {
+        Self { header, ..self }
+    }
+
+    /// Displays a footer below the list, with a hint about the controls.
+    ///
+    /// The hint is provided by the selected item, see [`MenuListItem::hint`], or by the input
+    /// adapter, see [`InputAdapter::hint`].
+    ///
+    /// [`MenuListItem::hint`]: crate::items::MenuListItem::hint
+    pub const fn with_footer(self) -> Self {
+        Self {
+            footer: true,
+            ..self
+        }
+    }
+
+    /// Sets how titles that don't fit next to the item's value are displayed.
+    ///
+    /// By default, titles are cut off. The title of the selected item is scrolled instead, if a
+    /// marquee is set using [`MenuStyle::with_marquee`].
+    pub const fn with_title_overflow(self, title_overflow: TitleOverflow) -> Self {
+        Self {
+            title_overflow,
+            ..self
+        }
+    }
+
+    /// Scrolls the title of the selected item back and forth if it doesn't fit its line.
+    ///
+    /// The marquee advances on every [`Menu::update`], and the menu keeps animating while the
+    /// selected title is scrolled.
+    pub const fn with_marquee(self, marquee: Marquee) -> Self {
+        Self {
+            marquee: Some(marquee),
+            ..self
+        }
+    }
+
+    /// Displays the description of the selected item, see [`MenuListItem::details`], once the
+    /// selection has not changed for `updates` calls to [`Menu::update`].
+    ///
+    /// The description replaces the hint in the footer if the footer is displayed, see
+    /// [`MenuStyle::with_footer`]. Otherwise, it is displayed in a popup at the bottom of the
+    /// list.
+    ///
+    /// [`MenuListItem::details`]: crate::items::MenuListItem::details
+    pub const fn with_details_delay(self, updates: u32) -> Self {
+        Self {
+            details_delay: Some(updates),
+            ..self
+        }
+    }
+
+    /// Sets how the list scrolls to keep the selected item visible.
+    ///
+    /// By default, the list moves by the full distance in a single update. Otherwise, the list
+    /// moves by one step for every 16 milliseconds passed to [`Menu::update_elapsed`].
+    pub const fn with_list_scrolling(self, scrolling: ListScrolling) -> Self {
+        Self { scrolling, ..self }
+    }
+
     pub const fn with_selection_indicator<S2>(
         self,
         indicator_style: S2,
-    ) -> MenuStyle<S2, IT, P, R, T>
+    ) -> MenuStyle<S2, IT, P, R, T, F, TF, SB>
     where
         S2: IndicatorStyle,
     {
         MenuStyle {
             theme: self.theme,
             scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
             font: self.font,
             title_font: self.title_font,
+            scrollbar_style: self.scrollbar_style,
             input_adapter: self.input_adapter,
             indicator: Indicator {
                 style: indicator_style,
@@ -126,7 +364,10 @@ where
         }
     }
 
-    pub const fn with_input_adapter<IT2>(self, input_adapter: IT2) -> MenuStyle<S, IT2, P, R, T>
+    pub const fn with_input_adapter<IT2>(
+        self,
+        input_adapter: IT2,
+    ) -> MenuStyle<S, IT2, P, R, T, F, TF, SB>
     where
         IT2: InputAdapterSource<R>,
     {
@@ -134,8 +375,15 @@ where
             theme: self.theme,
             input_adapter,
             scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
             font: self.font,
             title_font: self.title_font,
+            scrollbar_style: self.scrollbar_style,
             indicator: self.indicator,
             _marker: PhantomData,
         }
@@ -144,13 +392,20 @@ where
     pub const fn with_animated_selection_indicator(
         self,
         frames: i32,
-    ) -> MenuStyle<S, IT, AnimatedPosition, R, T> {
+    ) -> MenuStyle<S, IT, AnimatedPosition, R, T, F, TF, SB> {
         MenuStyle {
             theme: self.theme,
             input_adapter: self.input_adapter,
             scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
             font: self.font,
             title_font: self.title_font,
+            scrollbar_style: self.scrollbar_style,
             indicator: Indicator {
                 style: self.indicator.style,
                 controller: AnimatedPosition::new(frames),
@@ -159,12 +414,45 @@ where
         }
     }
 
-    pub fn text_style(&self) -> MonoTextStyle<'static, BinaryColor> {
-        MonoTextStyle::new(self.font, BinaryColor::On)
+    /// Animates the selection indicator, moving it to the selected item in `duration_ms`
+    /// milliseconds following `easing`. See [`AnimatedPosition::eased`].
+    pub const fn with_eased_selection_indicator(
+        self,
+        duration_ms: u32,
+        easing: Easing,
+    ) -> MenuStyle<S, IT, AnimatedPosition, R, T, F, TF, SB> {
+        MenuStyle {
+            theme: self.theme,
+            input_adapter: self.input_adapter,
+            scrollbar: self.scrollbar,
+            scrolling: self.scrolling,
+            header: self.header,
+            footer: self.footer,
+            title_overflow: self.title_overflow,
+            marquee: self.marquee,
+            details_delay: self.details_delay,
+            font: self.font,
+            title_font: self.title_font,
+            scrollbar_style: self.scrollbar_style,
+            indicator: Indicator {
+                style: self.indicator.style,
+                controller: AnimatedPosition::eased(duration_ms, easing),
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn text_style(&self) -> F {
+        let mut style = self.font;
+        style.set_text_color(Some(self.theme.text_color()));
+        style.set_background_color(None);
+        style
     }
 
-    pub fn title_style(&self) -> MonoTextStyle<'static, T::Color> {
-        MonoTextStyle::new(self.title_font, self.theme.text_color())
+    pub fn title_style(&self) -> TF {
+        let mut style = self.title_font;
+        style.set_text_color(Some(self.theme.text_color()));
+        style
     }
 }
 
@@ -177,10 +465,17 @@ where
     S: IndicatorStyle,
 {
     selected: usize,
+    selected_key: Option<u32>,
     list_offset: i32,
     interaction_state: IT::State,
     indicator_state: IndicatorState<P, S>,
     last_input_state: InputState,
+    navigation: NavigationStack,
+    /// The number of updates since the selection changed. Drives the marquee and delays the
+    /// item details.
+    selection_ticks: u32,
+    /// The time passed to [`Menu::update_elapsed`] that was too short to scroll the list.
+    scroll_carry_ms: u32,
 }
 
 impl<IT, P, S> Default for MenuState<IT, P, S>
@@ -192,10 +487,14 @@ where
     fn default() -> Self {
         Self {
             selected: 0,
+            selected_key: None,
             list_offset: Default::default(),
             interaction_state: Default::default(),
             indicator_state: Default::default(),
             last_input_state: InputState::Idle,
+            navigation: NavigationStack::new(),
+            selection_ticks: 0,
+            scroll_carry_ms: 0,
         }
     }
 }
@@ -229,21 +528,41 @@ where
         self.interaction_state = Default::default();
     }
 
-    fn set_selected_item<ITS, R, T>(
+    /// Returns the number of submenus currently open.
+    pub fn depth(&self) -> usize {
+        self.navigation.depth()
+    }
+
+    /// Returns the key of the selected item, if it has one. See [`MenuListItem::key`].
+    ///
+    /// [`MenuListItem::key`]: crate::items::MenuListItem::key
+    pub fn selected_key(&self) -> Option<u32> {
+        self.selected_key
+    }
+
+    fn select_item<VG, ITS, R, T, F, TF, SB>(
         &mut self,
-        selected: usize,
-        items: &impl MenuItemCollection<R>,
-        style: &MenuStyle<S, ITS, P, R, T>,
+        navigation: Navigation,
+        title: &str,
+        items: &VG,
+        style: &MenuStyle<S, ITS, P, R, T, F, TF, SB>,
     ) where
+        VG: MenuItemCollection<R, T::Color> + View,
         ITS: InputAdapterSource<R, InputAdapter = IT>,
         T: Theme,
     {
-        let selected =
-            Navigation::JumpTo(selected)
-                .calculate_selection(self.selected, items.count(), |i| items.selectable(i));
+        let (selected, selected_offset, selected_key) = visit_level(
+            title,
+            items,
+            self.navigation.path(),
+            SelectItem {
+                navigation,
+                current: self.selected,
+            },
+        );
         self.selected = selected;
-
-        let selected_offset = items.bounds_of(selected).top_left.y;
+        self.selected_key = selected_key;
+        self.selection_ticks = 0;
 
         style
             .indicator
@@ -251,91 +570,579 @@ where
     }
 }
 
-pub struct Menu<T, IT, VG, R, P, S, C>
+/// Runs `visitor` on the items of the (sub)menu selected by `path`.
+fn visit_level<R, C, VG, V>(title: &str, items: &VG, path: &[usize], visitor: V) -> V::Output
+where
+    C: PixelColor,
+    VG: MenuItemCollection<R, C> + View,
+    V: LevelVisitor<R, C>,
+{
+    match path.split_first() {
+        None => visitor.visit(title, items),
+        Some((&nth, path)) => items
+            .visit_submenu(nth, path, visitor)
+            .expect("Navigation path does not lead to a submenu"),
+    }
+}
+
+/// Runs `visitor` on the items of the (sub)menu selected by `path`.
+fn visit_level_mut<R, C, VG, V>(
+    title: &str,
+    items: &mut VG,
+    path: &[usize],
+    visitor: V,
+) -> V::Output
+where
+    C: PixelColor,
+    VG: MenuItemCollection<R, C> + View,
+    V: LevelVisitorMut<R, C>,
+{
+    match path.split_first() {
+        None => visitor.visit(title, items),
+        Some((&nth, path)) => items
+            .visit_submenu_mut(nth, path, visitor)
+            .expect("Navigation path does not lead to a submenu"),
+    }
+}
+
+/// Returns whether `path` leads to a submenu.
+fn is_valid_path<R, C, VG>(items: &VG, path: &[usize]) -> bool
+where
+    C: PixelColor,
+    VG: MenuItemCollection<R, C> + View,
+{
+    (1..=path.len()).all(|len| {
+        let (&nth, parent) = path[..len].split_last().unwrap();
+        visit_level("", items, parent, OpensSubmenu { nth })
+    })
+}
+
+/// Calculates the new selection and returns it, together with the selected item's position.
+struct SelectItem {
+    navigation: Navigation,
+    current: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for SelectItem {
+    type Output = (usize, i32, Option<u32>);
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        if items.count() == 0 {
+            return (0, 0, None);
+        }
+
+        let selected = self
+            .navigation
+            .calculate_selection(self.current, items.count(), |i| items.selectable(i));
+
+        (
+            selected,
+            items.bounds_of(selected).top_left.y,
+            items.key_of(selected),
+        )
+    }
+}
+
+/// Returns the number of items.
+struct ItemCount;
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for ItemCount {
+    type Output = usize;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.count()
+    }
+}
+
+/// Returns whether the `nth` item opens a submenu.
+struct OpensSubmenu {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for OpensSubmenu {
+    type Output = bool;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        self.nth < items.count() && items.visit_submenu(self.nth, &[], Noop).is_some()
+    }
+}
+
+struct Noop;
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for Noop {
+    type Output = ();
+
+    fn visit<L>(self, _title: &str, _items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+    }
+}
+
+struct ValueOf {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for ValueOf {
+    type Output = R;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.value_of(self.nth)
+    }
+}
+
+struct ConfirmationOf {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for ConfirmationOf {
+    type Output = Option<ConfirmDialog>;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.confirmation_of(self.nth)
+    }
+}
+
+struct DetailsOf {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for DetailsOf {
+    type Output = Option<&'static str>;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.details_of(self.nth)
+    }
+}
+
+struct InteractWith {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitorMut<R, C> for InteractWith {
+    type Output = R;
+
+    fn visit<L>(self, _title: &str, items: &mut L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.interact_with(self.nth)
+    }
+}
+
+struct LinkedItems {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for LinkedItems {
+    type Output = Range<usize>;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.linked_items(self.nth)
+    }
+}
+
+struct IsEditing {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitor<R, C> for IsEditing {
+    type Output = bool;
+
+    fn visit<L>(self, _title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.is_editing(self.nth)
+    }
+}
+
+struct BeginEdit {
+    nth: usize,
+}
+
+impl<R, C: PixelColor> LevelVisitorMut<R, C> for BeginEdit {
+    type Output = bool;
+
+    fn visit<L>(self, _title: &str, items: &mut L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.begin_edit(self.nth)
+    }
+}
+
+struct Edit {
+    nth: usize,
+    action: EditAction,
+}
+
+impl<R, C: PixelColor> LevelVisitorMut<R, C> for Edit {
+    type Output = Option<R>;
+
+    fn visit<L>(self, _title: &str, items: &mut L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C> + View,
+    {
+        items.edit(self.nth, self.action)
+    }
+}
+
+pub struct Menu<T, IT, VG, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     _return_type: PhantomData<R>,
     title: T,
     items: VG,
-    style: MenuStyle<S, IT, P, R, C>,
+    style: MenuStyle<S, IT, P, R, C, F, TF, SB>,
     state: MenuState<IT::InputAdapter, P, S>,
+    last_drawn: Option<DrawSnapshot<S::State>>,
+    changed_items: ChangedItems,
+    status: TextBuffer<STATUS_CAPACITY>,
+    dialog: Option<OpenDialog<R>>,
+    message_box: Option<MessageBox>,
+    /// The toast text, and the number of updates it is displayed for.
+    toast: Option<(TextBuffer<TOAST_CAPACITY>, u32)>,
+}
+
+/// The number of bytes of the header status text.
+const STATUS_CAPACITY: usize = 32;
+
+/// The number of bytes of the toast text.
+const TOAST_CAPACITY: usize = 32;
+
+/// A dialog displayed over the menu, and what happens when the user confirms it.
+struct OpenDialog<R> {
+    dialog: ConfirmDialog,
+    selected: usize,
+    action: DialogAction<R>,
+}
+
+enum DialogAction<R> {
+    /// Interact with the `nth` item of the current menu level.
+    Interact(usize),
+    /// Return the value.
+    Return(R),
+}
+
+impl<R> OpenDialog<R> {
+    fn new(dialog: ConfirmDialog, action: DialogAction<R>) -> Self {
+        Self {
+            dialog,
+            // Select the cancel button, so that confirming takes a deliberate action.
+            selected: CONFIRM + 1,
+            action,
+        }
+    }
+}
+
+/// Items whose appearance may have changed since the menu was last drawn.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChangedItems {
+    None,
+    One(usize),
+    Many,
+}
+
+impl ChangedItems {
+    fn add(self, nth: usize) -> Self {
+        match self {
+            Self::None => Self::One(nth),
+            Self::One(n) if n == nth => self,
+            _ => Self::Many,
+        }
+    }
+
+    fn add_all(self, range: Range<usize>) -> Self {
+        range.fold(self, Self::add)
+    }
+}
+
+/// The areas of a menu level.
+struct LevelLayout {
+    /// The area of the list items, without the scrollbar.
+    list_area: Rectangle,
+    scrollbar: Option<(Rectangle, ScrollPosition)>,
+}
+
+/// The properties of the drawn menu that are compared to find the changed areas.
+#[derive(Clone, Copy)]
+struct DrawSnapshot<ST> {
+    display_area: Rectangle,
+    navigation: NavigationStack,
+    list_offset: i32,
+    list_area: Rectangle,
+    padding: Insets,
+    selection_area: Rectangle,
+    indicator_state: ST,
+    input_state: InputState,
+    header_area: Option<Rectangle>,
+    status: TextBuffer<STATUS_CAPACITY>,
+    footer_area: Option<Rectangle>,
+    hint: &'static str,
+    details_popup: Option<(&'static str, Rectangle)>,
+    /// The highlighted option of the open popup picker, and the area of the picker.
+    picker: Option<(usize, Rectangle)>,
+    /// The open dialog, its selected button and its area.
+    dialog: Option<(ConfirmDialog, usize, Rectangle)>,
+    message_box: Option<(MessageBox, Rectangle)>,
+    toast: Option<(TextBuffer<TOAST_CAPACITY>, Rectangle)>,
+    marquee_offset: u32,
+}
+
+impl<ST: PartialEq> DrawSnapshot<ST> {
+    fn same_layout(&self, other: &Self) -> bool {
+        self.display_area == other.display_area
+            && self.navigation == other.navigation
+            && self.list_offset == other.list_offset
+            && self.list_area == other.list_area
+            && self.padding == other.padding
+    }
+
+    fn same_selection(&self, other: &Self) -> bool {
+        self.selection_area == other.selection_area
+            && self.indicator_state == other.indicator_state
+            && self.input_state == other.input_state
+            && self.marquee_offset == other.marquee_offset
+    }
 }
 
-impl<T, R, S, C> Menu<T, Programmed, NoItems, R, StaticPosition, S, C>
+impl<T, R, S, C, F, TF, SB> Menu<T, Programmed, NoItems, R, StaticPosition, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Creates a new menu builder with the given title.
-    pub fn build(title: T) -> MenuBuilder<T, Programmed, NoItems, R, StaticPosition, S, C>
+    pub fn build(
+        title: T,
+    ) -> MenuBuilder<T, Programmed, NoItems, R, StaticPosition, S, C, F, TF, SB>
     where
-        MenuStyle<S, Programmed, StaticPosition, R, C>: Default,
+        MenuStyle<S, Programmed, StaticPosition, R, C, F, TF, SB>: Default,
     {
         Self::with_style(title, MenuStyle::default())
     }
 }
 
-impl<T, IT, R, P, S, C> Menu<T, IT, NoItems, R, P, S, C>
+impl<T, IT, R, P, S, C, F, TF, SB> Menu<T, IT, NoItems, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     S: IndicatorStyle,
     IT: InputAdapterSource<R>,
     P: SelectionIndicatorController,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
     /// Creates a new menu builder with the given title and style.
     pub fn with_style(
         title: T,
-        style: MenuStyle<S, IT, P, R, C>,
-    ) -> MenuBuilder<T, IT, NoItems, R, P, S, C> {
+        style: MenuStyle<S, IT, P, R, C, F, TF, SB>,
+    ) -> MenuBuilder<T, IT, NoItems, R, P, S, C, F, TF, SB> {
         MenuBuilder::new(title, style)
     }
 }
 
-impl<T, IT, VG, R, P, S, C> Menu<T, IT, VG, R, P, S, C>
+impl<T, IT, VG, R, P, S, C, F, TF, SB> Menu<T, IT, VG, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
-    VG: MenuItemCollection<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
-    pub fn interact(&mut self, input: <IT::InputAdapter as InputAdapter>::Input) -> Option<R> {
-        let input = self
-            .style
-            .input_adapter
-            .adapter()
-            .handle_input(&mut self.state.interaction_state, input);
+    /// Runs `visitor` on the items of the currently open (sub)menu.
+    fn visit_level<V: LevelVisitor<R, C::Color>>(&self, visitor: V) -> V::Output {
+        visit_level(
+            self.title.as_ref(),
+            &self.items,
+            self.state.navigation.path(),
+            visitor,
+        )
+    }
 
-        self.state.last_input_state = match input {
-            InputResult::Interaction(_) => InputState::Idle,
-            InputResult::StateUpdate(state) => state,
-        };
+    /// Runs `visitor` on the items of the currently open (sub)menu.
+    fn visit_level_mut<V: LevelVisitorMut<R, C::Color>>(&mut self, visitor: V) -> V::Output {
+        visit_level_mut(
+            self.title.as_ref(),
+            &mut self.items,
+            self.state.navigation.path(),
+            visitor,
+        )
+    }
 
-        match input {
-            InputResult::Interaction(interaction) => match interaction {
-                Interaction::Navigation(navigation) => {
-                    let count = self.items.count();
-                    let new_selected =
-                        navigation.calculate_selection(self.state.selected, count, |i| {
-                            self.items.selectable(i)
-                        });
-                    if new_selected != self.state.selected {
-                        self.state
-                            .set_selected_item(new_selected, &self.items, &self.style);
+    fn edit_selected(&mut self, action: EditAction) -> Option<R> {
+        self.changed_items = self.changed_items.add(self.state.selected);
+        self.visit_level_mut(Edit {
+            nth: self.state.selected,
+            action,
+        })
+    }
+
+    fn interact_with_item(&mut self, nth: usize) -> R {
+        let linked = self.visit_level(LinkedItems { nth });
+        self.changed_items = self.changed_items.add_all(linked);
+        self.visit_level_mut(InteractWith { nth })
+    }
+
+    fn select_item(&mut self, navigation: Navigation) {
+        self.state
+            .select_item(navigation, self.title.as_ref(), &self.items, &self.style);
+    }
+
+    fn enter_submenu(&mut self) {
+        if !self.state.navigation.push(
+            self.state.selected,
+            self.state.selected_key,
+            self.state.list_offset,
+        ) {
+            return;
+        }
+
+        self.state.selected = 0;
+        self.state.list_offset = 0;
+        self.select_item(Navigation::Beginning);
+        self.style
+            .indicator
+            .jump_to_target(&mut self.state.indicator_state);
+    }
+
+    fn leave_submenu(&mut self) {
+        let Some((selected, _key, list_offset)) = self.state.navigation.pop() else {
+            return;
+        };
+
+        self.state.selected = selected;
+        self.state.list_offset = list_offset;
+        self.select_item(Navigation::JumpTo(selected));
+        self.style
+            .indicator
+            .jump_to_target(&mut self.state.indicator_state);
+    }
+
+    pub fn interact(&mut self, input: <IT::InputAdapter as InputAdapter>::Input) -> Option<R> {
+        let input = self
+            .style
+            .input_adapter
+            .adapter()
+            .handle_input(&mut self.state.interaction_state, input);
+
+        self.state.last_input_state = match input {
+            InputResult::Interaction(_) => InputState::Idle,
+            InputResult::StateUpdate(state) => state,
+        };
+
+        if self.message_box.is_some() {
+            return match input {
+                InputResult::Interaction(Interaction::Action(Action::Select)) => {
+                    self.message_box = None;
+                    None
+                }
+                InputResult::Interaction(Interaction::Action(Action::Return(value))) => {
+                    self.message_box = None;
+                    Some(value)
+                }
+                _ => None,
+            };
+        }
+
+        if self.dialog.is_some() {
+            return match input {
+                InputResult::Interaction(interaction) => self.interact_with_dialog(interaction),
+                _ => None,
+            };
+        }
+
+        let editing = self.visit_level(IsEditing {
+            nth: self.state.selected,
+        });
+
+        match input {
+            InputResult::Interaction(interaction) => match interaction {
+                Interaction::Navigation(navigation) if editing => {
+                    self.edit_selected(EditAction::Navigate(navigation))
+                }
+                Interaction::Navigation(navigation) => {
+                    let (new_selected, _, _) = self.visit_level(SelectItem {
+                        navigation,
+                        current: self.state.selected,
+                    });
+                    if new_selected != self.state.selected {
+                        self.select_item(Navigation::JumpTo(new_selected));
                     }
                     None
                 }
+                Interaction::Action(Action::Select) if editing => {
+                    self.edit_selected(EditAction::Select)
+                }
                 Interaction::Action(Action::Select) => {
-                    let value = self.items.interact_with(self.state.selected);
-                    Some(value)
+                    // An empty item source leaves nothing to select.
+                    if self.visit_level(ItemCount) == 0 {
+                        return None;
+                    }
+
+                    let selected = self.state.selected;
+                    if self.visit_level(OpensSubmenu { nth: selected }) {
+                        self.enter_submenu();
+                        return None;
+                    }
+
+                    if let Some(dialog) = self.visit_level(ConfirmationOf { nth: selected }) {
+                        self.dialog =
+                            Some(OpenDialog::new(dialog, DialogAction::Interact(selected)));
+                        return None;
+                    }
+
+                    self.changed_items = self.changed_items.add(selected);
+                    if self.visit_level_mut(BeginEdit { nth: selected }) {
+                        return None;
+                    }
+
+                    Some(self.interact_with_item(selected))
+                }
+                Interaction::Action(Action::Back) if editing => {
+                    self.edit_selected(EditAction::Cancel)
+                }
+                Interaction::Action(Action::Back) => {
+                    self.leave_submenu();
+                    None
                 }
                 Interaction::Action(Action::Return(value)) => Some(value),
             },
@@ -343,97 +1150,553 @@ where
         }
     }
 
+    fn interact_with_dialog(&mut self, interaction: Interaction<R>) -> Option<R> {
+        let open = self.dialog.as_mut()?;
+
+        match interaction {
+            Interaction::Navigation(navigation) => {
+                open.selected = navigation.calculate_selection(open.selected, 2, |_| true);
+                None
+            }
+            Interaction::Action(Action::Select) => {
+                let open = self.dialog.take()?;
+                if open.selected != CONFIRM {
+                    return None;
+                }
+
+                match open.action {
+                    DialogAction::Interact(nth) => Some(self.interact_with_item(nth)),
+                    DialogAction::Return(value) => Some(value),
+                }
+            }
+            Interaction::Action(Action::Back) => {
+                self.dialog = None;
+                None
+            }
+            Interaction::Action(Action::Return(value)) => {
+                self.dialog = None;
+                Some(value)
+            }
+        }
+    }
+
+    /// Opens `dialog` over the menu. While the dialog is open, it receives the user input.
+    ///
+    /// [`Menu::interact`] returns `value` if the user confirms the dialog. Opening a dialog
+    /// replaces the one that is already open.
+    pub fn open_dialog(&mut self, dialog: ConfirmDialog, value: R) {
+        self.dialog = Some(OpenDialog::new(dialog, DialogAction::Return(value)));
+    }
+
+    /// Returns whether a dialog is open.
+    pub fn is_dialog_open(&self) -> bool {
+        self.dialog.is_some()
+    }
+
+    /// Closes the open dialog without confirming it.
+    pub fn close_dialog(&mut self) {
+        self.dialog = None;
+    }
+
+    /// Displays `message_box` over the menu, and over any open dialog. The message box ignores
+    /// the user input until it is dismissed using [`Action::Select`].
+    pub fn show_message_box(&mut self, message_box: MessageBox) {
+        self.message_box = Some(message_box);
+    }
+
+    /// Returns whether a message box is displayed.
+    pub fn is_message_box_open(&self) -> bool {
+        self.message_box.is_some()
+    }
+
+    /// Displays `text` at the bottom of the list for `updates` calls to [`Menu::update`], e.g.
+    /// "Saved". Characters that don't fit 32 bytes are dropped.
+    ///
+    /// The toast does not block the user input. Showing a toast replaces the previous one.
+    pub fn show_toast(&mut self, text: &str, updates: u32) {
+        self.toast = (updates > 0).then(|| (TextBuffer::from_str_truncated(text), updates));
+    }
+
+    /// Returns the text of the displayed toast.
+    pub fn toast(&self) -> Option<&str> {
+        self.toast.as_ref().map(|(text, _)| text.as_str())
+    }
+
     pub fn state(&self) -> MenuState<IT::InputAdapter, P, S> {
         self.state
     }
 }
 
-impl<T, IT, VG, R, P, S, C> Menu<T, IT, VG, R, P, S, C>
+impl<T, IT, VG, R, P, S, C, F, TF, SB> Menu<T, IT, VG, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
-    R: Copy,
+    R: Clone,
     IT: InputAdapterSource<R>,
-    VG: MenuItemCollection<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
 {
+    /// Returns the value of the selected item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the open menu level has no items, e.g. if its only items come from an empty
+    /// [`ItemSource`](crate::collection::ItemSource).
     pub fn selected_value(&self) -> R {
-        self.items.value_of(self.state.selected)
+        self.visit_level(ValueOf {
+            nth: self.state.selected,
+        })
     }
 }
 
-impl<T, IT, VG, R, C, P, S> Menu<T, IT, VG, R, P, S, C>
+impl<T, IT, VG, R, C, P, S, F, TF, SB> Menu<T, IT, VG, R, P, S, C, F, TF, SB>
 where
     T: AsRef<str>,
     IT: InputAdapterSource<R>,
-    VG: ViewGroup + MenuItemCollection<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
     P: SelectionIndicatorController,
     S: IndicatorStyle,
     C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
 {
-    fn header<'t>(
+    /// Returns the area of the header, or `None` if the header is not displayed.
+    fn header_area(&self, title: &str, display_area: Rectangle) -> Option<Rectangle> {
+        self.style.header.area(
+            title,
+            self.status.as_str(),
+            self.style.title_font,
+            display_area,
+        )
+    }
+
+    /// Returns the area of the footer, or `None` if the footer is not displayed.
+    fn footer_area(&self, display_area: Rectangle) -> Option<Rectangle> {
+        if !self.style.footer {
+            return None;
+        }
+
+        // The hint text and a separator line above it
+        let height = self.style.font.line_height() + 1;
+        Some(display_area.resized_height(height.min(display_area.size().height), AnchorY::Bottom))
+    }
+
+    /// Returns the hint of the selected item, or the hint of the input adapter.
+    fn hint(&self, items: &impl MenuItemCollection<R, C::Color>) -> &'static str {
+        let selected = self.state.selected;
+
+        items.hint_of(selected).unwrap_or_else(|| {
+            self.style
+                .input_adapter
+                .adapter()
+                .hint(items.is_editing(selected))
+        })
+    }
+
+    /// Returns the description of the selected item, once it should be displayed.
+    fn details(&self, items: &impl MenuItemCollection<R, C::Color>) -> Option<&'static str> {
+        let delay = self.style.details_delay?;
+        if self.state.selection_ticks < delay {
+            return None;
+        }
+
+        items.details_of(self.state.selected)
+    }
+
+    /// Returns the text displayed in the footer: the description of the selected item, if
+    /// displayed, or the hint.
+    fn footer_text(&self, items: &impl MenuItemCollection<R, C::Color>) -> &'static str {
+        self.details(items).unwrap_or_else(|| self.hint(items))
+    }
+
+    /// Returns the description displayed in a popup at the bottom of `list_area`, together with
+    /// the area of the popup. The popup is only used if the footer is not displayed.
+    fn details_popup(
         &self,
-        title: &'t str,
-        display_area: Rectangle,
-    ) -> Option<impl View + 't + Drawable<Color = C::Color>>
+        items: &impl MenuItemCollection<R, C::Color>,
+        list_area: Rectangle,
+    ) -> Option<(&'static str, Rectangle)> {
+        if self.style.footer {
+            return None;
+        }
+
+        let details = self.details(items)?;
+
+        // The text is surrounded by a border and a one pixel gap.
+        let text = TextBox::with_textbox_style(
+            details,
+            list_area.offset(-2),
+            self.style.text_style(),
+            TextBoxStyleBuilder::new()
+                .height_mode(HeightMode::FitToText)
+                .build(),
+        );
+        let height = (text.bounds.size.height + 4).min(list_area.size().height);
+
+        Some((details, list_area.resized_height(height, AnchorY::Bottom)))
+    }
+
+    fn draw_details_popup<D>(
+        &self,
+        details: &str,
+        area: Rectangle,
+        display: &mut D,
+    ) -> Result<(), D::Error>
     where
-        C: Theme + 't,
+        D: DrawTarget<Color = C::Color>,
     {
-        if title.is_empty() {
+        let mut display = display.clipped(&area);
+
+        area.into_styled(
+            PrimitiveStyleBuilder::new()
+                .fill_color(self.style.theme.background_color())
+                .stroke_color(self.style.theme.text_color())
+                .stroke_width(1)
+                .build(),
+        )
+        .draw(&mut display)?;
+
+        TextBox::new(details, area.offset(-2), self.style.text_style()).draw(&mut display)?;
+
+        Ok(())
+    }
+
+    /// Returns the highlighted option of the selected item's popup picker, together with the area
+    /// of the picker, centered in `list_area`. Returns `None` if the picker is not open.
+    fn picker(
+        &self,
+        items: &impl MenuItemCollection<R, C::Color>,
+        list_area: Rectangle,
+    ) -> Option<(usize, Rectangle)> {
+        let text_style = self.style.text_style();
+
+        let mut count = 0;
+        let mut highlighted = 0;
+        let mut width = 0;
+        let open = items.picker_of(self.state.selected, &mut |option, is_highlighted| {
+            if is_highlighted {
+                highlighted = count;
+            }
+            width = width.max(text_width(option, &text_style));
+            count += 1;
+        });
+
+        if !open {
             return None;
         }
 
-        let text_style = self.style.title_style();
-        let thin_stroke = PrimitiveStyle::with_stroke(self.style.theme.text_color(), 1);
-        let header = LinearLayout::vertical(
-            Chain::new(TextBox::with_textbox_style(
-                title,
-                display_area,
-                text_style,
-                TextBoxStyle::with_height_mode(HeightMode::FitToText),
-            ))
-            .append(
-                // Bottom border
-                Line::new(
-                    display_area.top_left,
-                    display_area.anchor_point(AnchorPoint::TopRight),
-                )
-                .into_styled(thin_stroke),
+        // The options are surrounded by a border and a one pixel gap.
+        let line_height = text_style.line_height();
+        let rows = (count as u32)
+            .min(list_area.size().height.saturating_sub(4) / line_height)
+            .max(1);
+        let size = Size::new(
+            (width + 4).min(list_area.size().width),
+            (rows * line_height + 4).min(list_area.size().height),
+        );
+
+        Some((
+            highlighted,
+            Rectangle::new(Point::zero(), size).align_to(
+                &list_area,
+                horizontal::Center,
+                vertical::Center,
             ),
+        ))
+    }
+
+    fn draw_picker<D>(
+        &self,
+        items: &impl MenuItemCollection<R, C::Color>,
+        highlighted: usize,
+        area: Rectangle,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+    {
+        let mut display = display.clipped(&area);
+
+        area.into_styled(
+            PrimitiveStyleBuilder::new()
+                .fill_color(self.style.theme.background_color())
+                .stroke_color(self.style.theme.text_color())
+                .stroke_width(1)
+                .build(),
         )
-        .arrange();
+        .draw(&mut display)?;
+
+        let text_style = self.style.text_style();
+        let line_height = text_style.line_height();
+        let rows = (area.size().height.saturating_sub(4) / line_height).max(1) as usize;
+
+        let mut count: usize = 0;
+        items.picker_of(self.state.selected, &mut |_, _| count += 1);
+
+        // Scroll the list to keep the highlighted option in the middle.
+        let first = highlighted
+            .saturating_sub(rows / 2)
+            .min(count.saturating_sub(rows));
+
+        let mut idx = 0;
+        let mut result = Ok(());
+        items.picker_of(self.state.selected, &mut |option, is_highlighted| {
+            let row = idx;
+            idx += 1;
+
+            if result.is_err() || row < first || row >= first + rows {
+                return;
+            }
+
+            let row_area = Rectangle::new(
+                area.top_left + Point::new(2, 2 + ((row - first) as u32 * line_height) as i32),
+                Size::new(area.size().width.saturating_sub(4), line_height),
+            );
+
+            let mut style = text_style;
+            if is_highlighted {
+                result = row_area
+                    .into_styled(PrimitiveStyle::with_fill(
+                        self.style.theme.selection_color(),
+                    ))
+                    .draw(&mut display);
+                style.set_text_color(Some(self.style.theme.selected_text_color()));
+            }
+
+            if result.is_ok() {
+                result = Text::with_baseline(option, row_area.top_left, style, Baseline::Top)
+                    .draw(&mut display)
+                    .map(|_| ());
+            }
+        });
 
-        Some(header)
+        result
+    }
+
+    /// Returns the area of the toast: centered at the bottom of the display, above the footer and
+    /// the details popup, if displayed.
+    fn toast_area(
+        &self,
+        text: &str,
+        display_area: Rectangle,
+        details_popup: Option<Rectangle>,
+    ) -> Rectangle {
+        let bottom = match details_popup.or_else(|| self.footer_area(display_area)) {
+            Some(below) => below.top_left.y,
+            None => display_area.top_left.y + display_area.size().height as i32,
+        };
+
+        let text_style = self.style.text_style();
+        let size = Size::new(
+            (text_width(text, &text_style) + 4).min(display_area.size().width),
+            text_style.line_height() + 2,
+        );
+        let top_left = Point::new(
+            display_area.center().x - size.width as i32 / 2,
+            bottom - size.height as i32 - 2,
+        );
+
+        Rectangle::new(top_left, size)
+    }
+
+    fn draw_toast<D>(&self, text: &str, area: Rectangle, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+    {
+        let mut display = display.clipped(&area);
+
+        area.into_styled(PrimitiveStyle::with_fill(
+            self.style.theme.selection_color(),
+        ))
+        .draw(&mut display)?;
+
+        let mut text_style = self.style.text_style();
+        text_style.set_text_color(Some(self.style.theme.selected_text_color()));
+        Text::with_baseline(
+            text,
+            area.top_left + Point::new(2, 1),
+            text_style,
+            Baseline::Top,
+        )
+        .draw(&mut display)?;
+
+        Ok(())
+    }
+
+    fn draw_footer<D>(&self, hint: &str, area: Rectangle, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+    {
+        let mut display = display.clipped(&area);
+
+        Line::new(area.top_left, area.anchor_point(AnchorPoint::TopRight))
+            .into_styled(PrimitiveStyle::with_stroke(
+                self.style.theme.text_color(),
+                1,
+            ))
+            .draw(&mut display)?;
+
+        Text::with_baseline(
+            hint,
+            area.top_left + Point::new(0, 1),
+            self.style.text_style(),
+            Baseline::Top,
+        )
+        .draw(&mut display)?;
+
+        Ok(())
     }
 
     fn top_offset(&self) -> i32 {
         self.style.indicator.offset(&self.state.indicator_state) - self.state.list_offset
     }
 
+    /// Advances the animations by one frame.
+    ///
+    /// Time based animations, like the selection indicator and the list scrolling, assume that
+    /// 16 ms passed since the last update. Use [`Menu::update_elapsed`] to make them independent of the frame rate.
     pub fn update(&mut self, display: &impl Dimensions) {
+        self.update_elapsed(display, FRAME_TIME_MS);
+    }
+
+    /// Advances the animations by `elapsed_ms` milliseconds, or by one frame for animations that
+    /// are not time based.
+    ///
+    /// The selection indicator and the list scrolling use the elapsed time, animations that are
+    /// configured in frames advance by one frame for every 16 milliseconds. The indicator styles
+    /// like [`AnimatedTriangle`], the marquee, the details delay and toasts advance by one step
+    /// in every call, so their speed depends on how often this method is called.
+    ///
+    /// [`AnimatedTriangle`]: crate::selection_indicator::style::AnimatedTriangle
+    pub fn update_elapsed(&mut self, display: &impl Dimensions, elapsed_ms: u32) {
         // animations
-        self.style
-            .indicator
-            .update(self.state.last_input_state, &mut self.state.indicator_state);
+        self.style.indicator.update(
+            self.state.last_input_state,
+            elapsed_ms,
+            &mut self.state.indicator_state,
+        );
 
         // Ensure selection indicator is always visible by moving the menu list.
+        let list_offset_change = self.visit_level(ListOffsetChange {
+            menu: self,
+            display_area: display.bounding_box(),
+        });
+
+        // Move menu list.
+        self.state.list_offset += self.style.scrolling.step_elapsed(
+            list_offset_change,
+            &mut self.state.scroll_carry_ms,
+            elapsed_ms,
+        );
+
+        self.state.selection_ticks = self.state.selection_ticks.saturating_add(1);
+
+        if let Some((_, remaining)) = &mut self.toast {
+            *remaining -= 1;
+            if *remaining == 0 {
+                self.toast = None;
+            }
+        }
+    }
+
+    /// Returns whether calling [`Menu::update`] would change what the menu looks like.
+    ///
+    /// This is the case while the selection indicator or the list is moving, while the indicator
+    /// style is animated, while the title of the selected item is scrolled by a marquee, while the
+    /// description of the selected item is about to be displayed, while a toast is displayed, and
+    /// while a long press is in progress. Otherwise, the menu only needs to be redrawn after an
+    /// interaction, and the device can sleep until the next input.
+    pub fn is_animating(&self, display: &impl Dimensions) -> bool {
+        if matches!(self.state.last_input_state, InputState::InProgress(_))
+            || self.toast.is_some()
+            || self
+                .style
+                .indicator
+                .is_animating(self.state.last_input_state, &self.state.indicator_state)
+        {
+            return true;
+        }
+
+        let list_offset_change = self.visit_level(ListOffsetChange {
+            menu: self,
+            display_area: display.bounding_box(),
+        });
+
+        let details_pending = self
+            .style
+            .details_delay
+            .is_some_and(|delay| self.state.selection_ticks < delay)
+            && self
+                .visit_level(DetailsOf {
+                    nth: self.state.selected,
+                })
+                .is_some();
+
+        list_offset_change != 0
+            || details_pending
+            || self.visit_level(MarqueeOverflow {
+                menu: self,
+                display_area: display.bounding_box(),
+            }) > 0
+    }
+
+    /// Returns how many pixels the text of the selected item, scrolled by the marquee, is wider
+    /// than the space available to it. Returns 0 if the marquee is disabled.
+    fn marquee_overflow(
+        &self,
+        title: &str,
+        items: &(impl MenuItemCollection<R, C::Color> + View),
+        display_area: Rectangle,
+    ) -> u32 {
+        let Some(marquee) = self.style.marquee else {
+            return 0;
+        };
+
+        let layout = self.layout(title, items, display_area);
+        let selected_height = items.bounds_of(self.state.selected).size().height as i32;
+        let padding = self
+            .style
+            .indicator
+            .padding(selected_height, &self.state.indicator_state);
+        let width = (layout.list_area.size().width as i32 - padding.left - padding.right).max(0);
+
+        items.marquee_overflow(
+            self.state.selected,
+            &self.style.text_style(),
+            &marquee,
+            width as u32,
+        )
+    }
+
+    fn list_offset_change(
+        &self,
+        title: &str,
+        items: &impl MenuItemCollection<R, C::Color>,
+        display_area: Rectangle,
+    ) -> i32 {
         let top_distance = self.top_offset();
 
-        let list_offset_change = if top_distance > 0 {
-            let display_area = display.bounding_box();
+        if top_distance > 0 {
             let display_height = display_area.size().height as i32;
 
-            let header_height = if let Some(header) = self.header(self.title.as_ref(), display_area)
-            {
+            let header_height = if let Some(header) = self.header_area(title, display_area) {
                 header.size().height as i32
             } else {
                 0
             };
 
-            let selected_height = MenuItemCollection::bounds_of(&self.items, self.state.selected)
-                .size()
-                .height as i32;
+            let footer_height = if let Some(footer) = self.footer_area(display_area) {
+                footer.size().height as i32
+            } else {
+                0
+            };
+
+            let selected_height = items.bounds_of(self.state.selected).size().height as i32;
             let indicator_height = self
                 .style
                 .indicator
@@ -441,38 +1704,22 @@ where
 
             // Indicator is below display top. We only have to
             // move if indicator bottom is below display bottom.
-            (top_distance + indicator_height + header_height - display_height).max(0)
+            (top_distance + indicator_height + header_height + footer_height - display_height)
+                .max(0)
         } else {
             // We need to move up
             top_distance
-        };
-
-        // Move menu list.
-        self.state.list_offset += list_offset_change;
+        }
     }
-}
-
-impl<T, IT, VG, R, C, P, S> Drawable for Menu<T, IT, VG, R, P, S, C>
-where
-    T: AsRef<str>,
-    IT: InputAdapterSource<R>,
-    VG: ViewGroup + MenuItemCollection<R>,
-    P: SelectionIndicatorController,
-    S: IndicatorStyle,
-    C: Theme,
-{
-    type Color = C::Color;
-    type Output = ();
-
-    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
-    where
-        D: DrawTarget<Color = C::Color>,
-    {
-        let display_area = display.bounding_box();
 
-        let header = self.header(self.title.as_ref(), display_area);
-        let content_area = if let Some(header) = header {
-            header.draw(display)?;
+    /// Calculates the areas of the header, the list items and the scrollbar.
+    fn layout(
+        &self,
+        title: &str,
+        items: &(impl MenuItemCollection<R, C::Color> + View),
+        display_area: Rectangle,
+    ) -> LevelLayout {
+        let content_area = if let Some(header) = self.header_area(title, display_area) {
             display_area.resized_height(
                 display_area.size().height - header.size().height,
                 AnchorY::Bottom,
@@ -481,8 +1728,20 @@ where
             display_area
         };
 
+        let content_area = if let Some(footer) = self.footer_area(display_area) {
+            content_area.resized_height(
+                content_area
+                    .size()
+                    .height
+                    .saturating_sub(footer.size().height),
+                AnchorY::Top,
+            )
+        } else {
+            content_area
+        };
+
         let menu_height = content_area.size().height as i32;
-        let list_height = self.items.bounds().size().height as i32;
+        let list_height = items.bounds().size().height as i32;
 
         let draw_scrollbar = match self.style.scrollbar {
             DisplayScrollbar::Display => true,
@@ -490,44 +1749,613 @@ where
             DisplayScrollbar::Auto => list_height > menu_height,
         };
 
-        let menu_display_area = if draw_scrollbar {
-            let scrollbar_area = content_area.resized_width(2, AnchorX::Right);
-            let thin_stroke = PrimitiveStyle::with_stroke(self.style.theme.text_color(), 1);
+        if draw_scrollbar {
+            let position = ScrollPosition {
+                list_height: list_height as u32,
+                view_height: menu_height as u32,
+                offset: self.state.list_offset.max(0) as u32,
+            };
+            let scrollbar_width = self
+                .style
+                .scrollbar_style
+                .width(&position)
+                .min(content_area.size().width);
+            let scrollbar_area = content_area.resized_width(scrollbar_width, AnchorX::Right);
 
-            let scale = |value| value * menu_height / list_height;
+            LevelLayout {
+                list_area: content_area.resized_width(
+                    content_area.size().width - scrollbar_area.size().width,
+                    AnchorX::Left,
+                ),
+                scrollbar: Some((scrollbar_area, position)),
+            }
+        } else {
+            LevelLayout {
+                list_area: content_area,
+                scrollbar: None,
+            }
+        }
+    }
 
-            let scrollbar_height = scale(menu_height).max(1);
-            let mut scrollbar_display = display.cropped(&scrollbar_area);
+    fn draw_level<L, D>(&self, title: &str, items: &L, display: &mut D) -> Result<(), D::Error>
+    where
+        L: MenuItemCollection<R, C::Color> + View,
+        D: DrawTarget<Color = C::Color>,
+    {
+        let display_area = display.bounding_box();
 
-            // Start scrollbar from y=1, so we have a margin on top instead of bottom
-            Line::new(Point::new(0, 1), Point::new(0, scrollbar_height))
-                .into_styled(thin_stroke)
-                .translate(Point::new(1, scale(self.state.list_offset)))
-                .draw(&mut scrollbar_display)?;
+        self.style.header.draw(
+            title,
+            self.status.as_str(),
+            &self.style.theme,
+            self.style.title_font,
+            display,
+        )?;
 
-            content_area.resized_width(
-                content_area.size().width - scrollbar_area.size().width,
-                AnchorX::Left,
-            )
-        } else {
-            content_area
-        };
+        if let Some(footer_area) = self.footer_area(display_area) {
+            self.draw_footer(self.footer_text(items), footer_area, display)?;
+        }
 
-        let selected_menuitem_height =
-            MenuItemCollection::bounds_of(&self.items, self.state.selected)
-                .size()
-                .height as i32;
+        let layout = self.layout(title, items, display_area);
+
+        if let Some((scrollbar_area, position)) = layout.scrollbar {
+            let mut scrollbar_display = display.clipped(&scrollbar_area);
+            self.style.scrollbar_style.draw(
+                &position,
+                self.style.theme.text_color(),
+                &mut scrollbar_display.cropped(&scrollbar_area),
+            )?;
+        }
+
+        let selected_menuitem_height = items.bounds_of(self.state.selected).size().height as i32;
 
         self.style.indicator.draw(
             selected_menuitem_height,
             self.top_offset(),
             self.state.last_input_state,
-            display.cropped(&menu_display_area),
-            &self.items,
+            display.cropped(&layout.list_area),
+            items,
             &self.style,
             &self.state,
         )?;
 
+        let details_popup = self.details_popup(items, layout.list_area);
+        if let Some((details, area)) = details_popup {
+            self.draw_details_popup(details, area, display)?;
+        }
+
+        if let Some((highlighted, area)) = self.picker(items, layout.list_area) {
+            self.draw_picker(items, highlighted, area, display)?;
+        }
+
+        if let Some((text, _)) = &self.toast {
+            let area = self.toast_area(
+                text.as_str(),
+                display_area,
+                details_popup.map(|(_, area)| area),
+            );
+            self.draw_toast(text.as_str(), area, display)?;
+        }
+
+        if let Some(open) = &self.dialog {
+            open.dialog
+                .modal(open.selected)
+                .draw(&self.style.theme, self.style.font, display)?;
+        }
+
+        if let Some(message_box) = &self.message_box {
+            message_box
+                .modal()
+                .draw(&self.style.theme, self.style.font, display)?;
+        }
+
         Ok(())
     }
+
+    /// Records the properties of the menu that determine what is drawn.
+    fn snapshot(
+        &self,
+        title: &str,
+        items: &(impl MenuItemCollection<R, C::Color> + View),
+        display_area: Rectangle,
+    ) -> DrawSnapshot<S::State> {
+        let layout = self.layout(title, items, display_area);
+
+        let indicator = &self.style.indicator;
+        let indicator_state = &self.state.indicator_state;
+        let selected_height = items.bounds_of(self.state.selected).size().height as i32;
+        let details_popup = self.details_popup(items, layout.list_area);
+
+        DrawSnapshot {
+            display_area,
+            navigation: self.state.navigation,
+            list_offset: self.state.list_offset,
+            list_area: layout.list_area,
+            padding: indicator.padding(selected_height, indicator_state),
+            selection_area: Rectangle::new(
+                layout.list_area.top_left + Point::new(0, self.top_offset()),
+                Size::new(
+                    layout.list_area.size().width,
+                    indicator.item_height(selected_height, indicator_state) as u32,
+                ),
+            ),
+            indicator_state: indicator.style_state(indicator_state),
+            input_state: self.state.last_input_state,
+            header_area: self.header_area(title, display_area),
+            status: self.status,
+            footer_area: self.footer_area(display_area),
+            hint: self.footer_text(items),
+            details_popup,
+            picker: self.picker(items, layout.list_area),
+            dialog: self.dialog.as_ref().map(|open| {
+                let area = open
+                    .dialog
+                    .modal(open.selected)
+                    .area(self.style.font, display_area);
+                (open.dialog, open.selected, area)
+            }),
+            message_box: self.message_box.map(|message_box| {
+                let area = message_box.modal().area(self.style.font, display_area);
+                (message_box, area)
+            }),
+            toast: self.toast.map(|(text, _)| {
+                let area = self.toast_area(
+                    text.as_str(),
+                    display_area,
+                    details_popup.map(|(_, area)| area),
+                );
+                (text, area)
+            }),
+            marquee_offset: self.style.marquee.map_or(0, |marquee| {
+                let overflow = self.marquee_overflow(title, items, display_area);
+                marquee.offset(self.state.selection_ticks, overflow)
+            }),
+        }
+    }
+
+    /// Returns the changed areas of the display, and the snapshot to compare the next frame to.
+    fn find_changes(
+        &self,
+        title: &str,
+        items: &(impl MenuItemCollection<R, C::Color> + View),
+        display_area: Rectangle,
+    ) -> (DirtyRegions, DrawSnapshot<S::State>) {
+        let snapshot = self.snapshot(title, items, display_area);
+        let mut regions = DirtyRegions::new();
+
+        match self.last_drawn {
+            Some(last) if last.same_layout(&snapshot) => {
+                if last.status != snapshot.status {
+                    if let Some(header_area) = snapshot.header_area {
+                        regions.add(header_area);
+                    }
+                }
+
+                if last.hint != snapshot.hint {
+                    if let Some(footer_area) = snapshot.footer_area {
+                        regions.add(footer_area);
+                    }
+                }
+
+                if last.dialog != snapshot.dialog {
+                    for (_, _, area) in last.dialog.iter().chain(&snapshot.dialog) {
+                        regions.add(*area);
+                    }
+                }
+
+                if last.message_box != snapshot.message_box {
+                    for (_, area) in last.message_box.iter().chain(&snapshot.message_box) {
+                        regions.add(*area);
+                    }
+                }
+
+                if last.toast != snapshot.toast {
+                    for (_, area) in last.toast.iter().chain(&snapshot.toast) {
+                        regions.add(*area);
+                    }
+                }
+
+                if last.details_popup != snapshot.details_popup {
+                    for (_, area) in last.details_popup.iter().chain(&snapshot.details_popup) {
+                        regions.add(*area);
+                    }
+                }
+
+                if last.picker != snapshot.picker {
+                    for (_, area) in last.picker.iter().chain(&snapshot.picker) {
+                        regions.add(*area);
+                    }
+                }
+
+                if !last.same_selection(&snapshot) {
+                    regions.add(last.selection_area);
+                    regions.add(snapshot.selection_area);
+                }
+
+                match self.changed_items {
+                    ChangedItems::None => {}
+                    ChangedItems::One(nth) => {
+                        let bounds = items.bounds_of(nth);
+                        let top = snapshot.list_area.top_left.y + snapshot.padding.top
+                            - snapshot.list_offset
+                            + bounds.top_left.y;
+
+                        // Item text is drawn one pixel taller than the item.
+                        let row = Rectangle::new(
+                            Point::new(snapshot.list_area.top_left.x, top),
+                            Size::new(snapshot.list_area.size().width, bounds.size().height + 1),
+                        );
+                        regions.add(row.intersection(&snapshot.list_area));
+                    }
+                    ChangedItems::Many => regions.add(snapshot.list_area),
+                }
+            }
+            _ => regions.add(display_area),
+        }
+
+        (regions, snapshot)
+    }
+
+    /// Returns the areas of the display that changed since the last call to
+    /// [`Menu::draw_partial`].
+    ///
+    /// The whole display is reported as changed before the first partial draw, and after
+    /// [`Menu::invalidate`] was called.
+    pub fn dirty_regions(&self, display: &impl Dimensions) -> DirtyRegions {
+        let (regions, _) = self.visit_level(FindChanges {
+            menu: self,
+            display_area: display.bounding_box(),
+        });
+
+        regions
+    }
+
+    /// Redraws the areas of the display that changed since the last call to this method.
+    ///
+    /// The changed areas are cleared using [`Theme::background_color`] before drawing. Use the
+    /// returned regions to only transfer the changed parts of the frame buffer to the display.
+    ///
+    /// Drawing the menu using [`Drawable::draw`] does not update the state used to find the
+    /// changes. Call [`Menu::invalidate`] when mixing the two methods, or when the display
+    /// content has been changed by something else.
+    pub fn draw_partial<D>(&mut self, display: &mut D) -> Result<DirtyRegions, D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+    {
+        let (regions, snapshot) = self.visit_level(FindChanges {
+            menu: self,
+            display_area: display.bounding_box(),
+        });
+
+        if !regions.is_empty() {
+            let background = self.style.theme.background_color();
+            for region in regions.iter() {
+                display.fill_solid(region, background)?;
+            }
+
+            self.draw(&mut display.clip_to(&regions))?;
+        }
+
+        self.last_drawn = Some(snapshot);
+        self.changed_items = ChangedItems::None;
+
+        Ok(regions)
+    }
+
+    /// Sets the text displayed on the right side of the header, e.g. a clock or a battery
+    /// level. Characters that don't fit 32 bytes are dropped.
+    ///
+    /// The header is displayed if either the title or the status is not empty.
+    pub fn set_status(&mut self, status: &str) {
+        self.status = TextBuffer::from_str_truncated(status);
+    }
+
+    /// Returns the text displayed on the right side of the header.
+    pub fn status(&self) -> &str {
+        self.status.as_str()
+    }
+
+    /// Makes the next call to [`Menu::draw_partial`] redraw the whole display.
+    pub fn invalidate(&mut self) {
+        self.last_drawn = None;
+        self.changed_items = ChangedItems::None;
+    }
+}
+
+struct ListOffsetChange<'a, M> {
+    menu: &'a M,
+    display_area: Rectangle,
+}
+
+impl<T, IT, VG, R, C, P, S, F, TF, SB> LevelVisitor<R, C::Color>
+    for ListOffsetChange<'_, Menu<T, IT, VG, R, P, S, C, F, TF, SB>>
+where
+    T: AsRef<str>,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+{
+    type Output = i32;
+
+    fn visit<L>(self, title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C::Color> + View,
+    {
+        self.menu
+            .list_offset_change(title, items, self.display_area)
+    }
+}
+
+/// Calculates how many pixels the text of the selected item overflows by.
+struct MarqueeOverflow<'a, M> {
+    menu: &'a M,
+    display_area: Rectangle,
+}
+
+impl<T, IT, VG, R, C, P, S, F, TF, SB> LevelVisitor<R, C::Color>
+    for MarqueeOverflow<'_, Menu<T, IT, VG, R, P, S, C, F, TF, SB>>
+where
+    T: AsRef<str>,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+{
+    type Output = u32;
+
+    fn visit<L>(self, title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C::Color> + View,
+    {
+        self.menu.marquee_overflow(title, items, self.display_area)
+    }
+}
+
+struct FindChanges<'a, M> {
+    menu: &'a M,
+    display_area: Rectangle,
+}
+
+impl<T, IT, VG, R, C, P, S, F, TF, SB> LevelVisitor<R, C::Color>
+    for FindChanges<'_, Menu<T, IT, VG, R, P, S, C, F, TF, SB>>
+where
+    T: AsRef<str>,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+{
+    type Output = (DirtyRegions, DrawSnapshot<S::State>);
+
+    fn visit<L>(self, title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C::Color> + View,
+    {
+        self.menu.find_changes(title, items, self.display_area)
+    }
+}
+
+struct DrawLevel<'a, M, D> {
+    menu: &'a M,
+    display: &'a mut D,
+}
+
+impl<T, IT, VG, R, C, P, S, F, TF, SB, D> LevelVisitor<R, C::Color>
+    for DrawLevel<'_, Menu<T, IT, VG, R, P, S, C, F, TF, SB>, D>
+where
+    T: AsRef<str>,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+    D: DrawTarget<Color = C::Color>,
+{
+    type Output = Result<(), D::Error>;
+
+    fn visit<L>(self, title: &str, items: &L) -> Self::Output
+    where
+        L: MenuItemCollection<R, C::Color> + View,
+    {
+        self.menu.draw_level(title, items, self.display)
+    }
+}
+
+impl<T, IT, VG, R, C, P, S, F, TF, SB> Drawable for Menu<T, IT, VG, R, P, S, C, F, TF, SB>
+where
+    T: AsRef<str>,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+{
+    type Color = C::Color;
+    type Output = ();
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+    {
+        self.visit_level(DrawLevel {
+            menu: self,
+            display,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{assert_redrawn, has_pixel, menu_with_items, mock_display};
+
+    use embedded_graphics::{
+        geometry::AnchorY, pixelcolor::BinaryColor, prelude::Point, primitives::PointsIter,
+    };
+
+    use crate::{
+        interaction::{Interaction, Navigation},
+        Menu,
+    };
+
+    #[test]
+    fn footer_shows_hint_and_reserves_space() {
+        use crate::{items::MenuItem, MenuStyle};
+
+        let mut menu = menu_with_items(MenuStyle::new(BinaryColor::On).with_footer(), 4)
+            .add_menu_item(
+                MenuItem::new("Item 4", false)
+                    .with_value_converter(|_| 4)
+                    .with_hint("hold: toggle"),
+            )
+            .build();
+
+        let mut display = mock_display();
+
+        menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.last_drawn.unwrap().hint, "");
+
+        menu.interact(Interaction::Navigation(Navigation::End));
+        menu.update(&display);
+
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let snapshot = menu.last_drawn.unwrap();
+        let footer_area = snapshot.footer_area.unwrap();
+
+        assert_eq!(snapshot.hint, "hold: toggle");
+        assert_redrawn(&regions, footer_area);
+
+        // The separator line spans the display, the hint is drawn below it.
+        let separator = footer_area.resized_height(1, AnchorY::Top);
+        assert!(separator
+            .points()
+            .all(|point| display.get_pixel(point) == Some(BinaryColor::On)));
+        let hint_area = footer_area.resized_height(footer_area.size.height - 1, AnchorY::Bottom);
+        assert!(has_pixel(&display, hint_area, BinaryColor::On));
+        assert!(snapshot.selection_area.bottom_right().unwrap().y < footer_area.top_left.y);
+    }
+
+    #[test]
+    fn details_are_displayed_after_delay() {
+        use crate::{items::MenuItem, MenuStyle};
+
+        let item = || {
+            MenuItem::new("Erase", ())
+                .with_value_converter(|_| 0)
+                .with_detail_text("Erases all logs and restarts the device")
+        };
+
+        let mut menu = Menu::with_style(
+            "Root",
+            MenuStyle::new(BinaryColor::On).with_details_delay(3),
+        )
+        .add_menu_item(item())
+        .add_item("Other", (), |_| 1)
+        .build();
+
+        let mut display = mock_display();
+
+        for _ in 0..2 {
+            assert!(menu.is_animating(&display));
+            menu.update(&display);
+            menu.draw_partial(&mut display).unwrap();
+            assert_eq!(menu.last_drawn.unwrap().details_popup, None);
+        }
+
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+        let (details, popup_area) = menu.last_drawn.unwrap().details_popup.unwrap();
+        assert_eq!(details, "Erases all logs and restarts the device");
+
+        // The popup has a border, a cleared gap and the text inside.
+        let corner = popup_area.bottom_right().unwrap();
+        assert_eq!(
+            display.get_pixel(popup_area.top_left),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(display.get_pixel(corner), Some(BinaryColor::On));
+        assert_eq!(
+            display.get_pixel(popup_area.top_left + Point::new(1, 1)),
+            Some(BinaryColor::Off)
+        );
+        assert!(has_pixel(&display, popup_area.offset(-2), BinaryColor::On));
+        assert!(!menu.is_animating(&display));
+
+        // Changing the selection hides the popup.
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        menu.update(&display);
+        let regions = menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.last_drawn.unwrap().details_popup, None);
+        assert_redrawn(&regions, popup_area);
+
+        // With a footer, the details replace the hint.
+        let mut menu = Menu::with_style(
+            "Root",
+            MenuStyle::new(BinaryColor::On)
+                .with_footer()
+                .with_details_delay(0),
+        )
+        .add_menu_item(item())
+        .build();
+
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+        let snapshot = menu.last_drawn.unwrap();
+        assert_eq!(snapshot.details_popup, None);
+        assert_eq!(snapshot.hint, "Erases all logs and restarts the device");
+    }
+
+    #[test]
+    fn toast_is_stacked_above_details_popup() {
+        use crate::{
+            items::{MenuItem, SubMenu},
+            MenuStyle,
+        };
+        use embedded_layout::object_chain::Chain;
+
+        let mut menu = Menu::with_style(
+            "Root",
+            MenuStyle::new(BinaryColor::On).with_details_delay(0),
+        )
+        .add_menu_item(
+            SubMenu::new(
+                "Network",
+                Chain::new(MenuItem::new("Wi-Fi", ()).with_value_converter(|_| 1)),
+                0,
+            )
+            .with_detail_text("Wireless settings"),
+        )
+        .build();
+
+        let mut display = mock_display();
+
+        menu.show_toast("Saved", 2);
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+
+        let snapshot = menu.last_drawn.unwrap();
+        let (details, popup_area) = snapshot.details_popup.unwrap();
+        let (_, toast_area) = snapshot.toast.unwrap();
+        assert_eq!(details, "Wireless settings");
+        assert!(toast_area.intersection(&popup_area).is_zero_sized());
+        assert!(toast_area.bottom_right().unwrap().y < popup_area.top_left.y);
+    }
 }
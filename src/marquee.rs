@@ -0,0 +1,165 @@
+//! Displaying item titles that are too long to fit their line.
+
+/// How a title that does not fit next to the item's value is displayed, unless it is scrolled by
+/// a [`Marquee`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TitleOverflow {
+    /// Cuts the title off at the value.
+    #[default]
+    Clip,
+    /// Shortens the title and ends it with "...".
+    Ellipsis,
+}
+
+/// Scrolls the title of the selected item back and forth if it does not fit its line.
+///
+/// The title rests at both ends for a while, then scrolls by one pixel every `speed` updates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Marquee {
+    speed: u32,
+    pause: u32,
+    value: bool,
+}
+
+impl Default for Marquee {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Marquee {
+    /// Scrolls by one pixel every 2 updates, and rests for 30 updates at both ends.
+    pub const fn new() -> Self {
+        Self {
+            speed: 2,
+            pause: 30,
+            value: false,
+        }
+    }
+
+    /// Sets the number of updates it takes to scroll by one pixel.
+    pub const fn with_speed(self, updates_per_pixel: u32) -> Self {
+        Self {
+            speed: if updates_per_pixel == 0 {
+                1
+            } else {
+                updates_per_pixel
+            },
+            ..self
+        }
+    }
+
+    /// Sets the number of updates the title rests for at both ends.
+    pub const fn with_pause(self, updates: u32) -> Self {
+        Self {
+            pause: updates,
+            ..self
+        }
+    }
+
+    /// Scrolls the value together with the title, instead of keeping the value in place.
+    pub const fn with_value(self) -> Self {
+        Self {
+            value: true,
+            ..self
+        }
+    }
+
+    /// Returns whether the value scrolls together with the title.
+    pub const fn scrolls_value(&self) -> bool {
+        self.value
+    }
+
+    /// Returns how many pixels the text is scrolled by `ticks` updates after the item was
+    /// selected, if the text is `overflow` pixels wider than its area.
+    pub(crate) fn offset(&self, ticks: u32, overflow: u32) -> u32 {
+        if overflow == 0 {
+            return 0;
+        }
+
+        let travel = overflow.saturating_mul(self.speed);
+        let half_period = self.pause.saturating_add(travel);
+        let t = ticks % half_period.saturating_mul(2);
+
+        let (t, forward) = if t < half_period {
+            (t, true)
+        } else {
+            (t - half_period, false)
+        };
+
+        let moved = t.saturating_sub(self.pause) / self.speed;
+        if forward {
+            moved
+        } else {
+            overflow - moved
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Interaction, Navigation},
+        test_utils::{assert_redrawn, mock_display},
+        Menu,
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    use super::Marquee;
+
+    #[test]
+    fn scrolls_back_and_forth() {
+        let marquee = Marquee::new().with_speed(2).with_pause(3);
+
+        let offsets = (0..30)
+            .map(|ticks| marquee.offset(ticks, 4))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            offsets,
+            [
+                0, 0, 0, 0, 0, 1, 1, 2, 2, 3, 3, // rest, then scroll forward
+                4, 4, 4, 4, 4, 3, 3, 2, 2, 1, 1, // rest, then scroll back
+                0, 0, 0, 0, 0, 1, 1, 2,
+            ]
+        );
+        assert_eq!(marquee.offset(100, 0), 0);
+    }
+
+    #[test]
+    fn marquee_scrolls_overflowing_selected_title() {
+        use crate::{
+            marquee::{Marquee, TitleOverflow},
+            MenuStyle,
+        };
+
+        let style = MenuStyle::new(BinaryColor::On)
+            .with_title_overflow(TitleOverflow::Ellipsis)
+            .with_marquee(Marquee::new().with_speed(1).with_pause(0));
+
+        let mut menu = Menu::with_style("Root", style)
+            .add_item("This title is much too long", false, |_| 0)
+            .add_item("This one is too long as well", false, |_| 1)
+            .add_item("Short", false, |_| 2)
+            .build();
+
+        let mut display = mock_display();
+
+        menu.draw_partial(&mut display).unwrap();
+        assert!(menu.is_animating(&display));
+        assert_eq!(menu.last_drawn.unwrap().marquee_offset, 0);
+
+        menu.update(&display);
+        let regions = menu.draw_partial(&mut display).unwrap();
+        let snapshot = menu.last_drawn.unwrap();
+        assert_eq!(snapshot.marquee_offset, 1);
+        assert_redrawn(&regions, snapshot.selection_area);
+
+        // Selecting a title that fits stops the animation.
+        menu.interact(Interaction::Navigation(Navigation::End));
+        menu.update(&display);
+        menu.draw_partial(&mut display).unwrap();
+        assert_eq!(menu.last_drawn.unwrap().marquee_offset, 0);
+        assert!(!menu.is_animating(&display));
+    }
+}
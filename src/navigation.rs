@@ -0,0 +1,140 @@
+/// Maximum number of nested submenus the menu can keep track of.
+pub const MAX_DEPTH: usize = 8;
+
+/// Keeps track of the opened submenus, and the selection and scroll position of their parents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavigationStack {
+    depth: usize,
+    selected: [usize; MAX_DEPTH],
+    keys: [Option<u32>; MAX_DEPTH],
+    list_offset: [i32; MAX_DEPTH],
+}
+
+impl Default for NavigationStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationStack {
+    pub const fn new() -> Self {
+        Self {
+            depth: 0,
+            selected: [0; MAX_DEPTH],
+            keys: [None; MAX_DEPTH],
+            list_offset: [0; MAX_DEPTH],
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the indices of the opened submenu items, starting at the root menu.
+    pub fn path(&self) -> &[usize] {
+        &self.selected[..self.depth]
+    }
+
+    /// Saves the parent's state. `key` is the key of the item that opened the submenu, if it has
+    /// one. Returns `false` if the maximum depth has been reached.
+    pub fn push(&mut self, selected: usize, key: Option<u32>, list_offset: i32) -> bool {
+        if self.depth == MAX_DEPTH {
+            return false;
+        }
+
+        self.selected[self.depth] = selected;
+        self.keys[self.depth] = key;
+        self.list_offset[self.depth] = list_offset;
+        self.depth += 1;
+
+        true
+    }
+
+    /// Returns the parent's saved selection, the key of the selected item and the list offset.
+    pub fn pop(&mut self) -> Option<(usize, Option<u32>, i32)> {
+        if self.depth == 0 {
+            return None;
+        }
+
+        self.depth -= 1;
+
+        Some((
+            self.selected[self.depth],
+            self.keys[self.depth],
+            self.list_offset[self.depth],
+        ))
+    }
+
+    /// Updates the saved selections that have a key to the current position of the key.
+    ///
+    /// `find` receives the path to the level, the key and the saved selection, and returns the
+    /// position of the key. Entries without a key keep their saved selection. Returns the number
+    /// of levels that have been found, the submenus after them no longer exist.
+    pub fn resolve(
+        &mut self,
+        mut find: impl FnMut(&[usize], u32, usize) -> Option<usize>,
+    ) -> usize {
+        for level in 0..self.depth {
+            let Some(key) = self.keys[level] else {
+                continue;
+            };
+
+            match find(&self.selected[..level], key, self.selected[level]) {
+                Some(position) => self.selected[level] = position,
+                None => return level,
+            }
+        }
+
+        self.depth
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop_restores_parent_state() {
+        let mut stack = NavigationStack::new();
+
+        assert!(stack.push(3, Some(7), -10));
+        assert!(stack.push(1, None, 0));
+        assert_eq!(stack.path(), &[3, 1]);
+
+        assert_eq!(stack.pop(), Some((1, None, 0)));
+        assert_eq!(stack.pop(), Some((3, Some(7), -10)));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.path().is_empty());
+    }
+
+    #[test]
+    fn push_stops_at_max_depth() {
+        let mut stack = NavigationStack::new();
+
+        for i in 0..MAX_DEPTH {
+            assert!(stack.push(i, None, 0));
+        }
+        assert!(!stack.push(MAX_DEPTH, None, 0));
+        assert_eq!(stack.depth(), MAX_DEPTH);
+    }
+
+    #[test]
+    fn resolve_moves_keyed_entries() {
+        let mut stack = NavigationStack::new();
+
+        stack.push(1, Some(10), 0);
+        stack.push(2, None, 0);
+        stack.push(0, Some(30), 0);
+
+        let resolved = stack.resolve(|path, key, hint| match key {
+            10 => Some(hint + 1),
+            _ => {
+                assert_eq!(path, &[2, 2]);
+                None
+            }
+        });
+
+        assert_eq!(resolved, 2);
+        assert_eq!(stack.path(), &[2, 2, 0]);
+    }
+}
@@ -0,0 +1,209 @@
+use core::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::Poll,
+};
+
+use embedded_graphics::prelude::DrawTarget;
+use embedded_layout::view_group::ViewGroup;
+use futures_core::Stream;
+
+use crate::{
+    collection::MenuItemCollection,
+    dirty_regions::DirtyRegions,
+    interaction::{InputAdapter, InputAdapterSource},
+    scrollbar::ScrollbarStyle,
+    selection_indicator::{style::IndicatorStyle, SelectionIndicatorController},
+    theme::Theme,
+    Menu, MenuTextStyle,
+};
+
+type Input<IT, R> = <<IT as InputAdapterSource<R>>::InputAdapter as InputAdapter>::Input;
+
+impl<T, IT, VG, R, P, S, C, F, TF, SB> Menu<T, IT, VG, R, P, S, C, F, TF, SB>
+where
+    T: AsRef<str>,
+    R: Clone,
+    IT: InputAdapterSource<R>,
+    VG: ViewGroup + MenuItemCollection<R, C::Color>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    C: Theme,
+    F: MenuTextStyle<C::Color>,
+    TF: MenuTextStyle<C::Color>,
+    SB: ScrollbarStyle<C::Color>,
+{
+    /// Runs the menu until an item returns a value.
+    ///
+    /// The menu is redrawn using [`Menu::draw_partial`], after which `flush` is called with the
+    /// changed regions, unless nothing changed. `inputs` provides the input of the menu's input
+    /// adapter. While the menu [is animating], the menu is also updated and redrawn every time
+    /// the future returned by `tick` completes, e.g. a timer that expires after a frame period.
+    /// Otherwise, the runner only waits for input. Animations only advance when `tick` completes,
+    /// so their speed doesn't depend on how often input arrives.
+    ///
+    /// [is animating]: Menu::is_animating
+    ///
+    /// Returns `None` if `inputs` ends before a value is returned.
+    pub async fn run<D, I, TK, TKF>(
+        &mut self,
+        display: &mut D,
+        mut inputs: I,
+        mut tick: TK,
+        mut flush: impl FnMut(&mut D, &DirtyRegions),
+    ) -> Result<Option<R>, D::Error>
+    where
+        D: DrawTarget<Color = C::Color>,
+        I: Stream<Item = Input<IT, R>> + Unpin,
+        TK: FnMut() -> TKF,
+        TKF: Future<Output = ()>,
+    {
+        loop {
+            let regions = self.draw_partial(display)?;
+            if !regions.is_empty() {
+                flush(display, &regions);
+            }
+
+            let event = if self.is_animating(display) {
+                let mut tick = pin!(tick());
+                poll_fn(|cx| match Pin::new(&mut inputs).poll_next(cx) {
+                    Poll::Ready(input) => Poll::Ready(Some(input)),
+                    Poll::Pending => tick.as_mut().poll(cx).map(|_| None),
+                })
+                .await
+            } else {
+                Some(poll_fn(|cx| Pin::new(&mut inputs).poll_next(cx)).await)
+            };
+
+            match event {
+                // Animations only advance when a frame period has passed.
+                None => self.update(display),
+                Some(Some(input)) => {
+                    if let Some(value) = self.interact(input) {
+                        return Ok(Some(value));
+                    }
+                }
+                Some(None) => return Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{menu_with_items, mock_display};
+
+    use core::{
+        cell::Cell,
+        future::{pending, ready},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use std::collections::VecDeque;
+
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use futures::executor::block_on;
+    use futures_core::Stream;
+
+    use crate::{
+        interaction::{Action, Interaction, Navigation},
+        MenuStyle,
+    };
+
+    /// Yields the scripted inputs. `None` entries make the stream pending once.
+    struct Script(VecDeque<Option<Interaction<i32>>>);
+
+    impl Stream for Script {
+        type Item = Interaction<i32>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            match self.get_mut().0.pop_front() {
+                Some(Some(input)) => Poll::Ready(Some(input)),
+                Some(None) => {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+                None => Poll::Ready(None),
+            }
+        }
+    }
+
+    fn run(animation_frames: i32, script: &[Option<Interaction<i32>>]) -> (Option<i32>, usize) {
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_animated_selection_indicator(animation_frames),
+            3,
+        )
+        .build();
+
+        let mut display = mock_display();
+
+        let ticks = Cell::new(0);
+        let result = block_on(menu.run(
+            &mut display,
+            Script(script.iter().copied().collect()),
+            || {
+                ticks.set(ticks.get() + 1);
+                ready(())
+            },
+            |_, _| {},
+        ))
+        .unwrap();
+
+        (result, ticks.get())
+    }
+
+    #[test]
+    fn returns_selected_value() {
+        let script = [
+            Some(Interaction::Navigation(Navigation::Next)),
+            Some(Interaction::Action(Action::Select)),
+        ];
+
+        assert_eq!(run(1, &script).0, Some(1));
+        assert_eq!(run(1, &script[..1]).0, None);
+    }
+
+    #[test]
+    fn ticks_only_while_animating() {
+        let idle = [None, None, None, None, None, None];
+
+        // Nothing moves, only wait for input.
+        assert_eq!(run(1, &idle).1, 0);
+
+        // The indicator takes a few frames to reach the next item.
+        let mut moving = vec![None; 20];
+        moving[0] = Some(Interaction::Navigation(Navigation::Next));
+
+        let (_, ticks) = run(4, &moving);
+        assert!(ticks > 2, "{ticks}");
+        assert!(ticks < 10, "{ticks}");
+    }
+
+    #[test]
+    fn input_does_not_advance_animations() {
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_animated_selection_indicator(1),
+            2,
+        )
+        .build();
+
+        let mut display = mock_display();
+
+        let script = [
+            Some(Interaction::Navigation(Navigation::Next)),
+            Some(Interaction::Navigation(Navigation::Next)),
+            Some(Interaction::Navigation(Navigation::Next)),
+        ];
+        let result = block_on(menu.run(
+            &mut display,
+            Script(script.into_iter().collect()),
+            pending,
+            |_, _| {},
+        ))
+        .unwrap();
+
+        // The timer never expired, so the indicator is still waiting to move.
+        assert_eq!(result, None);
+        assert!(menu.is_animating(&display));
+    }
+}
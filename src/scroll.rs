@@ -0,0 +1,145 @@
+use crate::selection_indicator::FRAME_TIME_MS;
+
+/// Controls how the menu list scrolls to keep the selected item visible.
+///
+/// The parameters are given in frames of 16 milliseconds: the list moves by one step in every
+/// call to [`Menu::update`](crate::Menu::update), and by one step for every 16 milliseconds
+/// passed to [`Menu::update_elapsed`](crate::Menu::update_elapsed).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListScrolling {
+    /// Moves the list by the full distance in a single update.
+    Immediate,
+    /// Moves the list by at most the given number of pixels every frame.
+    Linear(u32),
+    /// Moves the list by a `1 / frames` fraction of the remaining distance every frame, which
+    /// slows the list down as it approaches its target.
+    EaseOut(u32),
+}
+
+impl ListScrolling {
+    /// Returns how much the list should move in the current update, given the remaining
+    /// `distance`.
+    pub(crate) fn step(&self, distance: i32) -> i32 {
+        match *self {
+            Self::Immediate => distance,
+            Self::Linear(speed) => {
+                let speed = speed.clamp(1, i32::MAX as u32) as i32;
+                distance.clamp(-speed, speed)
+            }
+            Self::EaseOut(frames) => {
+                // Round away from zero, so that the list always reaches its target.
+                let step = match distance.unsigned_abs() {
+                    0 => 0,
+                    remaining => ((remaining - 1) / frames.max(1) + 1) as i32,
+                };
+
+                if distance < 0 {
+                    step.wrapping_neg()
+                } else {
+                    step
+                }
+            }
+        }
+    }
+
+    /// Returns how much the list should move in `elapsed_ms` milliseconds, given the remaining
+    /// `distance`. `carry_ms` keeps the time that was too short for a step until the next update.
+    pub(crate) fn step_elapsed(&self, distance: i32, carry_ms: &mut u32, elapsed_ms: u32) -> i32 {
+        let mut time = carry_ms.saturating_add(elapsed_ms);
+        let mut moved = 0;
+
+        if *self == Self::Immediate {
+            moved = distance;
+        } else {
+            while time >= FRAME_TIME_MS && moved != distance {
+                moved += self.step(distance - moved);
+                time -= FRAME_TIME_MS;
+            }
+        }
+
+        *carry_ms = time % FRAME_TIME_MS;
+        moved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Interaction, Navigation},
+        test_utils::menu_with_items,
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    use super::ListScrolling;
+
+    fn frames_to_reach(scrolling: ListScrolling, mut distance: i32) -> usize {
+        let mut frames = 0;
+        while distance != 0 {
+            let step = scrolling.step(distance);
+            assert!(step.abs() <= distance.abs(), "Overshoot");
+            distance -= step;
+            frames += 1;
+        }
+        frames
+    }
+
+    #[test]
+    fn reaches_target() {
+        assert_eq!(frames_to_reach(ListScrolling::Immediate, 30), 1);
+        assert_eq!(frames_to_reach(ListScrolling::Linear(4), 30), 8);
+        assert_eq!(frames_to_reach(ListScrolling::Linear(4), -30), 8);
+        assert!(frames_to_reach(ListScrolling::EaseOut(4), 30) > 4);
+        assert!(frames_to_reach(ListScrolling::EaseOut(4), -30) > 4);
+    }
+
+    #[test]
+    fn extreme_parameters_reach_target() {
+        assert_eq!(frames_to_reach(ListScrolling::Linear(0), 3), 3);
+        assert_eq!(frames_to_reach(ListScrolling::Linear(u32::MAX), -30), 1);
+        assert_eq!(frames_to_reach(ListScrolling::EaseOut(0), 30), 1);
+        assert_eq!(frames_to_reach(ListScrolling::EaseOut(u32::MAX), 30), 30);
+    }
+
+    #[test]
+    fn elapsed_time_is_split_into_frames() {
+        let scrolling = ListScrolling::Linear(4);
+        let mut carry_ms = 0;
+
+        assert_eq!(scrolling.step_elapsed(30, &mut carry_ms, 10), 0);
+        assert_eq!(scrolling.step_elapsed(30, &mut carry_ms, 10), 4);
+        assert_eq!(carry_ms, 4);
+        assert_eq!(scrolling.step_elapsed(26, &mut carry_ms, 44), 12);
+        assert_eq!(scrolling.step_elapsed(14, &mut carry_ms, u32::MAX), 14);
+
+        assert_eq!(
+            ListScrolling::Immediate.step_elapsed(30, &mut carry_ms, 0),
+            30
+        );
+    }
+
+    #[test]
+    fn list_scrolls_over_multiple_frames() {
+        use crate::MenuStyle;
+
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_list_scrolling(ListScrolling::EaseOut(4)),
+            7,
+        )
+        .build();
+
+        let display = MockDisplay::<BinaryColor>::new();
+        menu.update(&display);
+
+        menu.interact(Interaction::Navigation(Navigation::End));
+
+        let mut offsets = vec![];
+        while menu.is_animating(&display) {
+            menu.update(&display);
+            offsets.push(menu.state.list_offset);
+            assert!(offsets.len() < 20, "Scrolling does not end");
+        }
+
+        assert!(offsets.len() > 2, "{offsets:?}");
+        assert!(offsets.windows(2).all(|w| w[0] < w[1]), "{offsets:?}");
+    }
+}
@@ -0,0 +1,428 @@
+//! Scrollbar styles.
+//!
+//! The scrollbar is drawn to the right of the menu list. Whether it is displayed at all is
+//! controlled by [`DisplayScrollbar`](crate::DisplayScrollbar), its appearance is controlled by a
+//! [`ScrollbarStyle`] implementation set using
+//! [`MenuStyle::with_scrollbar`](crate::MenuStyle::with_scrollbar).
+
+use embedded_graphics::{
+    pixelcolor::PixelColor,
+    prelude::{DrawTarget, Point, Primitive, Size},
+    primitives::{Circle, Line, PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+    Drawable,
+};
+
+use crate::{buffer::TextBuffer, items::text_width, MenuTextStyle};
+
+/// The scroll position of the menu list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScrollPosition {
+    /// The height of the whole list.
+    pub list_height: u32,
+    /// The height of the visible part of the list.
+    pub view_height: u32,
+    /// The distance between the top of the list and the top of the visible part.
+    pub offset: u32,
+}
+
+impl ScrollPosition {
+    /// Returns the number of screens necessary to display the whole list.
+    pub fn pages(&self) -> u32 {
+        if self.view_height == 0 {
+            return 1;
+        }
+
+        let full_pages = self.list_height / self.view_height;
+        let partial_page = full_pages * self.view_height < self.list_height;
+
+        (full_pages + partial_page as u32).max(1)
+    }
+
+    /// Returns the 1-based index of the visible page. The last page is only reported when the
+    /// list is scrolled to the bottom.
+    pub fn page(&self) -> u32 {
+        if self.view_height == 0 || self.offset + self.view_height >= self.list_height {
+            self.pages()
+        } else {
+            self.offset / self.view_height + 1
+        }
+    }
+
+    /// Scales `value` from list coordinates to a scrollbar that is `height` pixels tall.
+    fn scale(&self, value: u32, height: u32) -> u32 {
+        if self.list_height <= self.view_height {
+            return if value == 0 { 0 } else { height };
+        }
+
+        (value as u64 * height as u64 / self.list_height as u64) as u32
+    }
+}
+
+/// Draws the scroll position next to the menu list.
+pub trait ScrollbarStyle<C>: Copy
+where
+    C: PixelColor,
+{
+    /// Returns the width of the area reserved for the scrollbar.
+    fn width(&self, position: &ScrollPosition) -> u32;
+
+    /// Draws the scrollbar. The display is cropped to the scrollbar area.
+    fn draw<D>(&self, position: &ScrollPosition, color: C, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>;
+}
+
+/// The part of a [`Bar`] that represents the visible part of the list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Thumb {
+    /// A vertical line at the right edge of the scrollbar.
+    Line,
+    /// A filled rectangle.
+    Filled,
+}
+
+/// A scroll bar whose thumb represents the visible part of the list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bar {
+    width: u32,
+    track: bool,
+    thumb: Thumb,
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bar {
+    /// A two pixels wide scrollbar with a line thumb and no track.
+    pub const fn new() -> Self {
+        Self {
+            width: 2,
+            track: false,
+            thumb: Thumb::Line,
+        }
+    }
+
+    /// Sets the width of the scrollbar area, including a one pixel gap next to the list.
+    pub const fn with_width(self, width: u32) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Draws an outline around the area the thumb moves in.
+    pub const fn with_track(self) -> Self {
+        Self {
+            track: true,
+            ..self
+        }
+    }
+
+    pub const fn with_thumb(self, thumb: Thumb) -> Self {
+        Self { thumb, ..self }
+    }
+}
+
+impl<C> ScrollbarStyle<C> for Bar
+where
+    C: PixelColor,
+{
+    fn width(&self, _position: &ScrollPosition) -> u32 {
+        self.width
+    }
+
+    fn draw<D>(&self, position: &ScrollPosition, color: C, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let height = display.bounding_box().size.height;
+        let thin_stroke = PrimitiveStyle::with_stroke(color, 1);
+
+        let thumb_area = if self.track {
+            Rectangle::new(
+                Point::new(1, 0),
+                Size::new(self.width.saturating_sub(1), height),
+            )
+            .into_styled(thin_stroke)
+            .draw(display)?;
+
+            Rectangle::new(
+                Point::new(2, 1),
+                Size::new(self.width.saturating_sub(3), height.saturating_sub(2)),
+            )
+        } else {
+            Rectangle::new(
+                Point::new(1, 0),
+                Size::new(self.width.saturating_sub(1), height),
+            )
+        };
+
+        let area_height = thumb_area.size.height;
+        let thumb_top = position.scale(position.offset, area_height) as i32;
+        let thumb_height = position.scale(position.view_height, area_height).max(1);
+
+        match self.thumb {
+            Thumb::Line => {
+                // Start the line one pixel lower, so we have a margin on top instead of bottom
+                let x = thumb_area.top_left.x + thumb_area.size.width as i32 - 1;
+                let top = thumb_area.top_left.y + thumb_top;
+
+                Line::new(
+                    Point::new(x, top + 1),
+                    Point::new(x, top + thumb_height as i32),
+                )
+                .into_styled(thin_stroke)
+                .draw(display)
+            }
+            Thumb::Filled => Rectangle::new(
+                thumb_area.top_left + Point::new(0, thumb_top),
+                Size::new(thumb_area.size.width, thumb_height),
+            )
+            .into_styled(PrimitiveStyle::with_fill(color))
+            .draw(display),
+        }
+    }
+}
+
+/// Displays the visible and total number of pages as text, e.g. `2/5`, at the bottom of the
+/// scrollbar area.
+#[derive(Clone, Copy, Debug)]
+pub struct PageCounter<F> {
+    text_style: F,
+}
+
+impl<F> PageCounter<F> {
+    /// Draws the counter using `text_style`. The text color is taken from the menu theme.
+    pub const fn new(text_style: F) -> Self {
+        Self { text_style }
+    }
+
+    fn text(current: u32, pages: u32) -> TextBuffer<12> {
+        TextBuffer::format(format_args!("{current}/{pages}"))
+    }
+}
+
+impl<C, F> ScrollbarStyle<C> for PageCounter<F>
+where
+    C: PixelColor,
+    F: MenuTextStyle<C>,
+{
+    fn width(&self, position: &ScrollPosition) -> u32 {
+        let pages = position.pages();
+
+        // Reserve space for the widest page number, and a gap next to the list
+        text_width(Self::text(pages, pages).as_str(), &self.text_style) + 1
+    }
+
+    fn draw<D>(&self, position: &ScrollPosition, color: C, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let area = display.bounding_box();
+        let text = Self::text(position.page(), position.pages());
+
+        let mut style = self.text_style;
+        style.set_text_color(Some(color));
+        style.set_background_color(None);
+
+        let width = text_width(text.as_str(), &style) as i32;
+        let bottom_right = area.top_left + area.size - Size::new(1, 1);
+
+        Text::with_baseline(
+            text.as_str(),
+            Point::new(bottom_right.x + 1 - width, bottom_right.y),
+            style,
+            Baseline::Bottom,
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
+
+/// Displays a column of dots, one for each page. The dot of the visible page is filled.
+///
+/// If there are more pages than dots that fit the height of the scrollbar, only the dots around
+/// the visible page are displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dots {
+    diameter: u32,
+    spacing: u32,
+}
+
+impl Default for Dots {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dots {
+    /// Dots with a diameter of 3 pixels and 2 pixels between them.
+    pub const fn new() -> Self {
+        Self {
+            diameter: 3,
+            spacing: 2,
+        }
+    }
+
+    pub const fn with_diameter(self, diameter: u32) -> Self {
+        Self { diameter, ..self }
+    }
+
+    pub const fn with_spacing(self, spacing: u32) -> Self {
+        Self { spacing, ..self }
+    }
+}
+
+impl<C> ScrollbarStyle<C> for Dots
+where
+    C: PixelColor,
+{
+    fn width(&self, _position: &ScrollPosition) -> u32 {
+        self.diameter + 1
+    }
+
+    fn draw<D>(&self, position: &ScrollPosition, color: C, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let height = display.bounding_box().size.height;
+        let pages = position.pages();
+        let current = position.page();
+
+        let pitch = (self.diameter + self.spacing).max(1);
+        let dots = pages.min(((height + self.spacing) / pitch).max(1));
+        let first = current.saturating_sub(dots / 2).clamp(1, pages - dots + 1);
+
+        let column_height = dots * pitch - self.spacing;
+        let top = height.saturating_sub(column_height) as i32 / 2;
+
+        for (dot, page) in (first..first + dots).enumerate() {
+            let style = if page == current {
+                PrimitiveStyle::with_fill(color)
+            } else {
+                PrimitiveStyle::with_stroke(color, 1)
+            };
+
+            Circle::new(
+                Point::new(1, top + dot as i32 * pitch as i32),
+                self.diameter,
+            )
+            .into_styled(style)
+            .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utils::{menu_with_items, mock_display};
+
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Dimensions, DrawTargetExt, Point, Size},
+        primitives::Rectangle,
+        Drawable,
+    };
+
+    use super::{Dots, ScrollPosition, ScrollbarStyle};
+
+    #[test]
+    fn pages() {
+        let position = |offset| ScrollPosition {
+            list_height: 100,
+            view_height: 40,
+            offset,
+        };
+
+        assert_eq!(position(0).pages(), 3);
+        assert_eq!(position(0).page(), 1);
+        assert_eq!(position(40).page(), 2);
+        assert_eq!(position(59).page(), 2);
+        assert_eq!(position(60).page(), 3);
+    }
+
+    #[test]
+    fn dots_only_draw_what_fits() {
+        // A million pages, the 500th of which is visible.
+        let position = ScrollPosition {
+            list_height: 1_000_000 * 20,
+            view_height: 20,
+            offset: 499 * 20,
+        };
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let area = Rectangle::new(Point::zero(), Size::new(4, 20));
+        Dots::new()
+            .draw(&position, BinaryColor::On, &mut display.cropped(&area))
+            .unwrap();
+
+        // 4 dots fit, the one of the visible page is the third one.
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(1, 1), Size::new(3, 18))
+        );
+        assert_eq!(display.get_pixel(Point::new(2, 12)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(2, 7)), None);
+    }
+
+    #[test]
+    fn scrollbar_styles_reserve_their_width() {
+        use crate::{
+            scrollbar::{Bar, Dots, PageCounter, ScrollbarStyle, Thumb},
+            FindChanges, MenuStyle,
+        };
+        use embedded_graphics::{
+            mono_font::{ascii::FONT_4X6, MonoTextStyle},
+            prelude::Point,
+        };
+
+        fn list_width<SB>(scrollbar: SB) -> (u32, MockDisplay<BinaryColor>)
+        where
+            SB: ScrollbarStyle<BinaryColor>,
+        {
+            let menu =
+                menu_with_items(MenuStyle::new(BinaryColor::On).with_scrollbar(scrollbar), 7)
+                    .build();
+
+            let mut display = mock_display();
+            menu.draw(&mut display).unwrap();
+
+            let (_, snapshot) = menu.visit_level(FindChanges {
+                menu: &menu,
+                display_area: display.bounding_box(),
+            });
+
+            (snapshot.list_area.size.width, display)
+        }
+
+        assert_eq!(list_width(Bar::new()).0, 62);
+        assert_eq!(list_width(Dots::new()).0, 60);
+        // "2/2" is 12 pixels wide, plus a gap
+        assert_eq!(
+            list_width(PageCounter::new(MonoTextStyle::new(
+                &FONT_4X6,
+                BinaryColor::On
+            )))
+            .0,
+            51
+        );
+
+        let (width, display) = list_width(
+            Bar::new()
+                .with_width(5)
+                .with_track()
+                .with_thumb(Thumb::Filled),
+        );
+        assert_eq!(width, 59);
+        // Track outline
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(60, 63)), Some(BinaryColor::On));
+        // The thumb is at the top of the track, the bottom of the track is empty
+        assert_eq!(display.get_pixel(Point::new(61, 62)), None);
+    }
+}
@@ -0,0 +1,59 @@
+/// The progress values used by [`Easing::apply`] range from 0 to `SCALE`.
+pub(crate) const SCALE: i32 = 1000;
+
+/// The shape of an animation over its duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Accelerates, then slows down before reaching the target.
+    EaseInOutCubic,
+    /// Overshoots the target, then settles back, like a spring.
+    Spring,
+}
+
+impl Easing {
+    /// Maps the elapsed fraction of the animation to the fraction of the distance travelled.
+    /// Both fractions are scaled to `0..=SCALE`. The result may be outside of that range for
+    /// curves that overshoot.
+    pub(crate) fn apply(&self, t: i32) -> i32 {
+        let t = t.clamp(0, SCALE) as i64;
+        let scale = SCALE as i64;
+
+        let eased = match self {
+            Self::Linear => t,
+            Self::EaseInOutCubic => {
+                if t < scale / 2 {
+                    4 * t * t * t / (scale * scale)
+                } else {
+                    let u = 2 * (scale - t);
+                    scale - u * u * u / (2 * scale * scale)
+                }
+            }
+            Self::Spring => {
+                // "Ease out back" with the usual 1.70158 overshoot constant.
+                let u = t - scale;
+                scale + 2702 * u * u * u / (scale * scale * scale) + 1702 * u * u / (scale * scale)
+            }
+        };
+
+        eased as i32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Easing, SCALE};
+
+    #[test]
+    fn curves_start_and_end_at_limits() {
+        for easing in [Easing::Linear, Easing::EaseInOutCubic, Easing::Spring] {
+            assert_eq!(easing.apply(0), 0, "{easing:?}");
+            assert_eq!(easing.apply(SCALE), SCALE, "{easing:?}");
+        }
+
+        assert_eq!(Easing::EaseInOutCubic.apply(SCALE / 2), SCALE / 2);
+        assert!(Easing::EaseInOutCubic.apply(SCALE / 4) < SCALE / 4);
+        assert!((SCALE / 2..SCALE).any(|t| Easing::Spring.apply(t) > SCALE));
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
-    adapters::color_map::BinaryColorDrawTargetExt,
+    adapters::color_map::ColorMappingDrawTargetExt,
     collection::MenuItemCollection,
     interaction::{InputAdapterSource, InputState},
+    items::DrawContext,
     margin::Insets,
+    scrollbar::ScrollbarStyle,
     selection_indicator::style::IndicatorStyle,
     theme::Theme,
-    MenuState, MenuStyle,
+    MenuState, MenuStyle, MenuTextStyle,
 };
 use embedded_graphics::{
     prelude::{DrawTarget, DrawTargetExt, Point, Size},
@@ -13,8 +15,14 @@ use embedded_graphics::{
     transform::Transform,
 };
 
+pub mod easing;
 pub mod style;
 
+pub use easing::Easing;
+
+/// The time that passes between two calls of [`Menu::update`](crate::Menu::update).
+pub(crate) const FRAME_TIME_MS: u32 = 16;
+
 pub trait SelectionIndicatorController: Copy {
     type State: Default + Copy;
 
@@ -22,6 +30,21 @@ pub trait SelectionIndicatorController: Copy {
     fn jump_to_target(&self, state: &mut Self::State);
     fn offset(&self, state: &Self::State) -> i32;
     fn update(&self, state: &mut Self::State);
+
+    /// Advances the animation by `elapsed_ms` milliseconds.
+    ///
+    /// Controllers that animate by frames ignore the elapsed time, which is the default.
+    fn update_elapsed(&self, state: &mut Self::State, _elapsed_ms: u32) {
+        self.update(state);
+    }
+
+    /// Returns whether [`SelectionIndicatorController::update`] would change the state.
+    fn is_moving(&self, state: &Self::State) -> bool {
+        let mut next = *state;
+        self.update(&mut next);
+
+        self.offset(&next) != self.offset(state)
+    }
 }
 
 #[derive(Clone, Copy, Default)]
@@ -48,20 +71,52 @@ impl SelectionIndicatorController for StaticPosition {
     fn update(&self, _state: &mut Self::State) {}
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Animation {
+    /// Moves a `1 / frames` fraction of the remaining distance every 16 milliseconds.
+    Decay { frames: i32 },
+    /// Follows `easing` from the starting point to the target in `duration_ms` milliseconds.
+    Eased { duration_ms: u32, easing: Easing },
+}
+
 #[derive(Clone, Copy)]
 pub struct AnimatedPosition {
-    frames: i32,
+    animation: Animation,
 }
 
 #[derive(Clone, Copy, Default)]
 pub struct AnimatedState {
+    start: i32,
     current: i32,
     target: i32,
+    elapsed_ms: u32,
 }
 
 impl AnimatedPosition {
+    /// Moves the indicator a `1 / frames` fraction of the remaining distance in every frame of
+    /// 16 milliseconds.
+    ///
+    /// [`Menu::update`](crate::Menu::update) advances the animation by a single frame,
+    /// [`Menu::update_elapsed`](crate::Menu::update_elapsed) by as many frames as fit the elapsed
+    /// time.
     pub const fn new(frames: i32) -> Self {
-        Self { frames }
+        Self {
+            animation: Animation::Decay { frames },
+        }
+    }
+
+    /// Moves the indicator to its target in `duration_ms` milliseconds, following `easing`.
+    ///
+    /// The animation is driven by the elapsed time passed to
+    /// [`Menu::update_elapsed`](crate::Menu::update_elapsed), so it takes the same time
+    /// regardless of the frame rate.
+    pub const fn eased(duration_ms: u32, easing: Easing) -> Self {
+        Self {
+            animation: Animation::Eased {
+                duration_ms,
+                easing,
+            },
+        }
     }
 }
 
@@ -69,10 +124,15 @@ impl SelectionIndicatorController for AnimatedPosition {
     type State = AnimatedState;
 
     fn update_target(&self, state: &mut Self::State, y: i32) {
-        state.target = y;
+        if state.target != y {
+            state.start = state.current;
+            state.target = y;
+            state.elapsed_ms = 0;
+        }
     }
 
     fn jump_to_target(&self, state: &mut Self::State) {
+        state.start = state.target;
         state.current = state.target;
     }
 
@@ -81,14 +141,57 @@ impl SelectionIndicatorController for AnimatedPosition {
     }
 
     fn update(&self, state: &mut Self::State) {
-        let rounding = if state.current < state.target {
-            self.frames - 1
-        } else {
-            1 - self.frames
-        };
+        self.update_elapsed(state, FRAME_TIME_MS);
+    }
 
-        let distance = state.target - state.current;
-        state.current += (distance + rounding) / self.frames;
+    fn update_elapsed(&self, state: &mut Self::State, elapsed_ms: u32) {
+        match self.animation {
+            Animation::Decay { frames } => {
+                let mut time = state.elapsed_ms.saturating_add(elapsed_ms);
+
+                while time >= FRAME_TIME_MS && state.current != state.target {
+                    let rounding = if state.current < state.target {
+                        frames - 1
+                    } else {
+                        1 - frames
+                    };
+
+                    let distance = state.target - state.current;
+                    state.current += (distance + rounding) / frames;
+                    time -= FRAME_TIME_MS;
+                }
+
+                // Keep the time that was too short for a frame for the next update.
+                state.elapsed_ms = time % FRAME_TIME_MS;
+            }
+            Animation::Eased {
+                duration_ms,
+                easing,
+            } => {
+                state.elapsed_ms = state.elapsed_ms.saturating_add(elapsed_ms).min(duration_ms);
+
+                let progress = if duration_ms == 0 {
+                    easing::SCALE
+                } else {
+                    (state.elapsed_ms as u64 * easing::SCALE as u64 / duration_ms as u64) as i32
+                };
+
+                let distance = (state.target - state.start) as i64;
+                let travelled = distance * easing.apply(progress) as i64 / easing::SCALE as i64;
+                state.current = state.start + travelled as i32;
+            }
+        }
+    }
+
+    fn is_moving(&self, state: &Self::State) -> bool {
+        match self.animation {
+            Animation::Decay { .. } => state.current != state.target,
+            // Springs pass the target before the animation ends.
+            Animation::Eased { duration_ms, .. } => {
+                state.current != state.target
+                    || (state.start != state.target && state.elapsed_ms < duration_ms)
+            }
+        }
     }
 }
 
@@ -155,24 +258,42 @@ where
         self.controller.jump_to_target(&mut state.position);
     }
 
-    pub fn update(&self, input_state: InputState, state: &mut State<P, S>) {
-        self.controller.update(&mut state.position);
+    pub fn update(&self, input_state: InputState, elapsed_ms: u32, state: &mut State<P, S>) {
+        self.controller
+            .update_elapsed(&mut state.position, elapsed_ms);
         self.style.update(&mut state.state, input_state);
     }
 
+    /// Returns whether [`Indicator::update`] would move the indicator or change its appearance.
+    pub fn is_animating(&self, input_state: InputState, state: &State<P, S>) -> bool {
+        let mut next = state.state;
+        self.style.update(&mut next, input_state);
+
+        self.controller.is_moving(&state.position) || next != state.state
+    }
+
+    pub fn padding(&self, menuitem_height: i32, state: &State<P, S>) -> Insets {
+        self.style.padding(&state.state, menuitem_height)
+    }
+
+    pub fn style_state(&self, state: &State<P, S>) -> S::State {
+        state.state
+    }
+
     pub fn item_height(&self, menuitem_height: i32, state: &State<P, S>) -> i32 {
         let indicator_insets = self.style.padding(&state.state, menuitem_height);
         menuitem_height + indicator_insets.top + indicator_insets.bottom
     }
 
-    pub fn draw<R, D, IT, C>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw<R, D, IT, C, F, TF, SB>(
         &self,
         selected_height: i32,
         selected_offset: i32,
         input_state: InputState,
         mut display: D,
-        items: &impl MenuItemCollection<R>,
-        style: &MenuStyle<S, IT, P, R, C>,
+        items: &impl MenuItemCollection<R, C::Color>,
+        style: &MenuStyle<S, IT, P, R, C, F, TF, SB>,
         menu_state: &MenuState<IT::InputAdapter, P, S>,
     ) -> Result<(), D::Error>
     where
@@ -181,6 +302,9 @@ where
         P: SelectionIndicatorController,
         C: Theme,
         S: IndicatorStyle,
+        F: MenuTextStyle<C::Color>,
+        TF: MenuTextStyle<C::Color>,
+        SB: ScrollbarStyle<C::Color>,
     {
         let display_size = display.bounding_box().size;
 
@@ -211,7 +335,7 @@ where
 
         // Translate inverting area to its position
         let mapping_area = selection_area.translate(selected_item_area.top_left);
-        let mut inverting = display.map_colors(
+        let mut inverting = display.map_color(
             &mapping_area,
             style.theme.text_color(),
             style.theme.selected_text_color(),
@@ -224,11 +348,127 @@ where
             Size::new(content_width, display_size.height),
         );
 
+        let context = DrawContext::new(
+            Some(menu_state.selected),
+            style.title_overflow,
+            style.marquee,
+            menu_state.selection_ticks,
+        );
+
         items.draw_styled(
             &style.text_style(),
+            &context,
             &mut inverting
                 .clipped(&content_area)
                 .translated(content_area.top_left - Point::new(0, menu_state.list_offset)),
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        interaction::{Interaction, Navigation},
+        test_utils::menu_with_items,
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    use super::{AnimatedPosition, AnimatedState, SelectionIndicatorController};
+
+    #[test]
+    fn decay_follows_elapsed_time() {
+        let controller = AnimatedPosition::new(4);
+
+        let mut by_frame = AnimatedState::default();
+        let mut by_time = AnimatedState::default();
+        controller.update_target(&mut by_frame, 100);
+        controller.update_target(&mut by_time, 100);
+
+        controller.update(&mut by_frame);
+        controller.update(&mut by_frame);
+        controller.update(&mut by_frame);
+
+        controller.update_elapsed(&mut by_time, 10);
+        assert_eq!(controller.offset(&by_time), 0);
+        controller.update_elapsed(&mut by_time, 38);
+        assert_eq!(controller.offset(&by_time), controller.offset(&by_frame));
+    }
+
+    #[test]
+    fn animation_ends_when_indicator_reaches_target() {
+        use crate::{selection_indicator::style::AnimatedTriangle, MenuStyle};
+
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_animated_selection_indicator(2),
+            2,
+        )
+        .build();
+
+        let display = MockDisplay::<BinaryColor>::new();
+
+        menu.update(&display);
+        assert!(!menu.is_animating(&display));
+
+        menu.interact(Interaction::Navigation(Navigation::Next));
+        assert!(menu.is_animating(&display));
+
+        let mut frames = 0;
+        while menu.is_animating(&display) {
+            menu.update(&display);
+            frames += 1;
+            assert!(frames < 10, "Animation does not end");
+        }
+        assert!(frames > 1);
+
+        // The animated triangle keeps pulsing
+        let mut menu = menu_with_items(
+            MenuStyle::new(BinaryColor::On).with_selection_indicator(AnimatedTriangle::new(20)),
+            1,
+        )
+        .build();
+
+        for _ in 0..30 {
+            menu.update(&display);
+            assert!(menu.is_animating(&display));
+        }
+    }
+
+    #[test]
+    fn eased_animation_is_independent_of_frame_rate() {
+        use crate::{selection_indicator::Easing, MenuStyle};
+
+        let display = MockDisplay::<BinaryColor>::new();
+        let run = |easing, frame_time| {
+            let mut menu = menu_with_items(
+                MenuStyle::new(BinaryColor::On).with_eased_selection_indicator(200, easing),
+                3,
+            )
+            .build();
+
+            menu.update(&display);
+            menu.interact(Interaction::Navigation(Navigation::End));
+
+            let mut offsets = vec![];
+            let mut elapsed = 0;
+            while menu.is_animating(&display) {
+                menu.update_elapsed(&display, frame_time);
+                elapsed += frame_time;
+                if elapsed % 64 == 0 {
+                    offsets.push(menu.top_offset());
+                }
+                assert!(elapsed < 1000, "Animation does not end");
+            }
+
+            (offsets, elapsed)
+        };
+
+        for easing in [Easing::Linear, Easing::EaseInOutCubic, Easing::Spring] {
+            let (offsets_60fps, elapsed_60fps) = run(easing, 16);
+            let (offsets_30fps, elapsed_30fps) = run(easing, 32);
+
+            assert_eq!(offsets_60fps, offsets_30fps, "{easing:?}");
+            assert!((200..232).contains(&elapsed_60fps), "{easing:?}");
+            assert!((200..232).contains(&elapsed_30fps), "{easing:?}");
+        }
+    }
+}
@@ -13,6 +13,10 @@ use crate::{
     theme::Theme,
 };
 
+/// A triangle indicator that periodically nudges to the left while there is no input.
+///
+/// The animation is `period` updates long, it advances by one step per update and ignores the
+/// elapsed time passed to [`Menu::update_elapsed`](crate::Menu::update_elapsed).
 #[derive(Clone, Copy)]
 pub struct AnimatedTriangle {
     period: i32,
@@ -24,7 +28,7 @@ impl AnimatedTriangle {
     }
 }
 
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, PartialEq)]
 pub struct State {
     current: i32,
 }
@@ -18,6 +18,7 @@ pub use border::Border;
 pub use line::Line;
 pub use triangle::Triangle;
 
+#[allow(clippy::manual_checked_ops)]
 pub fn interpolate(value: u32, x_min: u32, x_max: u32, y_min: u32, y_max: u32) -> u32 {
     let x_range = x_max - x_min;
     let y_range = y_max - y_min;
@@ -34,7 +35,7 @@ pub fn interpolate(value: u32, x_min: u32, x_max: u32, y_min: u32, y_max: u32) -
 
 pub trait IndicatorStyle: Copy {
     type Shape: ContainsPoint + Transform + Clone;
-    type State: Default + Copy;
+    type State: Default + Copy + PartialEq;
 
     fn on_target_changed(&self, _state: &mut Self::State) {}
     fn update(&self, _state: &mut Self::State, _input_state: InputState) {}
@@ -0,0 +1,192 @@
+//! Fixtures and helpers shared by the tests of the crate.
+
+use embedded_graphics::{
+    mock_display::MockDisplay,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, Point, Primitive, Size},
+    primitives::{PointsIter, PrimitiveStyle, Rectangle},
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline,
+    },
+    Drawable,
+};
+use embedded_layout::object_chain::Chain;
+
+use crate::{
+    builder::MenuBuilder,
+    collection::MenuItems,
+    dirty_regions::DirtyRegions,
+    interaction::InputAdapterSource,
+    items::{menu_item::SelectValue, MenuItem},
+    scrollbar::ScrollbarStyle,
+    selection_indicator::{style::IndicatorStyle, SelectionIndicatorController},
+    Menu, MenuStyle, MenuTextStyle,
+};
+
+/// The value of the items created by [`menu_with_items`], returned as the item's index.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Index(i32);
+
+impl SelectValue for Index {
+    fn marker(&self) -> &str {
+        ""
+    }
+}
+
+pub(crate) type Items =
+    MenuItems<Vec<MenuItem<String, i32, Index, true>>, MenuItem<String, i32, Index, true>, i32>;
+
+/// Starts a menu titled "Root" with `count` items. Item `n` is titled "Item n" and returns `n`.
+pub(crate) fn menu_with_items<IT, P, S, F, TF, SB>(
+    style: MenuStyle<S, IT, P, i32, BinaryColor, F, TF, SB>,
+    count: i32,
+) -> MenuBuilder<&'static str, IT, Chain<Items>, i32, P, S, BinaryColor, F, TF, SB>
+where
+    IT: InputAdapterSource<i32>,
+    P: SelectionIndicatorController,
+    S: IndicatorStyle,
+    F: MenuTextStyle<BinaryColor>,
+    TF: MenuTextStyle<BinaryColor>,
+    SB: ScrollbarStyle<BinaryColor>,
+{
+    let items = (0..count)
+        .map(|n| MenuItem::new(format!("Item {n}"), Index(n)).with_value_converter(|Index(n)| n))
+        .collect::<Vec<_>>();
+
+    Menu::with_style("Root", style).add_menu_items(items)
+}
+
+/// Returns a display that may be drawn over multiple times, like a real one.
+pub(crate) fn mock_display() -> MockDisplay<BinaryColor> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display
+}
+
+/// Asserts that `area` is completely covered by one of the redrawn `regions`.
+#[track_caller]
+pub(crate) fn assert_redrawn(regions: &DirtyRegions, area: Rectangle) {
+    assert!(
+        regions
+            .iter()
+            .any(|region| region.intersection(&area) == area),
+        "{area:?} was not redrawn, the redrawn regions are {:?}",
+        regions.iter().collect::<Vec<_>>()
+    );
+}
+
+/// Returns whether any pixel of `area` is drawn using `color`.
+pub(crate) fn has_pixel(
+    display: &MockDisplay<BinaryColor>,
+    area: Rectangle,
+    color: BinaryColor,
+) -> bool {
+    area.points()
+        .any(|point| display.get_pixel(point) == Some(color))
+}
+
+/// A proportional text style that draws every character as a filled box. Narrow characters
+/// are 2 pixels wide, all others 6 pixels, including the gap after them.
+#[derive(Clone, Copy)]
+pub(crate) struct Proportional {
+    color: Option<BinaryColor>,
+    line_height: u32,
+}
+
+impl Proportional {
+    pub(crate) fn new(line_height: u32) -> Self {
+        Self {
+            color: None,
+            line_height,
+        }
+    }
+
+    fn advance(c: char) -> u32 {
+        match c {
+            'i' | 'l' | '.' | ' ' => 2,
+            _ => 6,
+        }
+    }
+
+    fn top_left(&self, position: Point, baseline: Baseline) -> Point {
+        let bottom = self.line_height as i32 - 1;
+        match baseline {
+            Baseline::Top => position,
+            Baseline::Middle => position - Point::new(0, bottom / 2),
+            Baseline::Bottom | Baseline::Alphabetic => position - Point::new(0, bottom),
+        }
+    }
+}
+
+impl TextRenderer for Proportional {
+    type Color = BinaryColor;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut top_left = self.top_left(position, baseline);
+        for c in text.chars() {
+            let advance = Self::advance(c);
+            if let (Some(color), false) = (self.color, c == ' ') {
+                Rectangle::new(top_left, Size::new(advance - 1, self.line_height - 1))
+                    .into_styled(PrimitiveStyle::with_fill(color))
+                    .draw(target)?;
+            }
+            top_left.x += advance as i32;
+        }
+
+        Ok(Point::new(top_left.x, position.y))
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        _baseline: Baseline,
+        _target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        Ok(position + Point::new(width as i32, 0))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = text.chars().map(Self::advance).sum::<u32>();
+
+        TextMetrics {
+            bounding_box: Rectangle::new(
+                self.top_left(position, baseline),
+                Size::new(width, self.line_height),
+            ),
+            next_position: position + Point::new(width as i32, 0),
+        }
+    }
+
+    fn line_height(&self) -> u32 {
+        self.line_height
+    }
+}
+
+impl CharacterStyle for Proportional {
+    type Color = BinaryColor;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.color = text_color;
+    }
+}
+
+/// Holds the touch for `time` updates then releases it, returning the first selected value.
+pub(crate) fn touch<R>(mut interact: impl FnMut(bool) -> Option<R>, time: u32) -> Option<R> {
+    let held = (0..time).fold(None, |value, _| value.or(interact(true)));
+    let released = interact(false);
+    held.or(released)
+}
@@ -6,6 +6,13 @@ pub trait Theme: Copy {
     fn text_color(&self) -> Self::Color;
     fn selected_text_color(&self) -> Self::Color;
     fn selection_color(&self) -> Self::Color;
+
+    /// The color used to clear the areas redrawn by [`Menu::draw_partial`].
+    ///
+    /// [`Menu::draw_partial`]: crate::Menu::draw_partial
+    fn background_color(&self) -> Self::Color {
+        Self::Color::default()
+    }
 }
 
 impl Theme for BinaryColor {
@@ -22,4 +29,8 @@ impl Theme for BinaryColor {
     fn selection_color(&self) -> Self::Color {
         *self
     }
+
+    fn background_color(&self) -> Self::Color {
+        self.invert()
+    }
 }
@@ -0,0 +1,115 @@
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_menu::interaction::{Action, Interaction, Navigation};
+
+#[test]
+fn derived_menu_applies_changes() {
+    use embedded_menu::{MenuStyle, SelectValue};
+
+    #[derive(Clone, Copy, PartialEq, Debug, SelectValue)]
+    enum Mode {
+        Slow,
+        Fast,
+    }
+
+    #[derive(embedded_menu::Menu)]
+    #[menu(title = "Settings")]
+    struct Settings {
+        #[menu(label = "Enabled")]
+        enabled: bool,
+        #[menu(section = "Advanced")]
+        mode: Mode,
+        #[menu(min = 1, max = 3)]
+        level: u8,
+        #[menu(skip)]
+        id: u32,
+    }
+
+    let mut settings = Settings {
+        enabled: false,
+        mode: Mode::Slow,
+        level: 2,
+        id: 7,
+    };
+
+    let mut menu = settings
+        .menu_builder(MenuStyle::new(BinaryColor::On))
+        .build();
+
+    for _ in 0..2 {
+        if let Some(change) = menu.interact(Interaction::Action(Action::Select)) {
+            settings.apply(change);
+        }
+        menu.interact(Interaction::Navigation(Navigation::Next));
+    }
+    assert!(menu.selected_value() == SettingsEvent::Level(2));
+
+    // Edit the numeric item
+    menu.interact(Interaction::Action(Action::Select));
+    menu.interact(Interaction::Navigation(Navigation::Next));
+    let change = menu.interact(Interaction::Action(Action::Select)).unwrap();
+    settings.apply(change);
+
+    assert!(settings.enabled);
+    assert_eq!(settings.mode, Mode::Fast);
+    assert_eq!(settings.level, 3);
+    assert_eq!(settings.id, 7);
+}
+
+#[test]
+fn derived_menu_clones_non_copy_fields() {
+    use embedded_menu::{MenuStyle, SelectValue};
+
+    #[derive(Clone, PartialEq, Debug, SelectValue)]
+    enum Profile {
+        Quiet,
+        Loud,
+    }
+
+    #[derive(embedded_menu::Menu)]
+    struct Settings {
+        profile: Profile,
+    }
+
+    let mut settings = Settings {
+        profile: Profile::Quiet,
+    };
+
+    let mut menu = settings
+        .menu_builder(MenuStyle::new(BinaryColor::On))
+        .build();
+
+    let change = menu.interact(Interaction::Action(Action::Select)).unwrap();
+    settings.apply(change);
+
+    assert_eq!(settings.profile, Profile::Loud);
+}
+
+#[test]
+fn derived_menu_supports_generic_structs() {
+    use embedded_menu::{items::menu_item::SelectValue, MenuStyle};
+
+    #[derive(embedded_menu::Menu)]
+    struct Settings<V, E>
+    where
+        V: SelectValue,
+    {
+        value: V,
+        #[menu(skip)]
+        extra: E,
+    }
+
+    let mut settings = Settings {
+        value: false,
+        extra: "unused",
+    };
+
+    let mut menu = settings
+        .menu_builder(MenuStyle::new(BinaryColor::On))
+        .build();
+
+    let change = menu.interact(Interaction::Action(Action::Select)).unwrap();
+    settings.apply(change);
+
+    assert!(settings.value);
+    assert_eq!(settings.extra, "unused");
+}